@@ -0,0 +1,101 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{
+    error::Error,
+    input::{next_field, parse_lines, parse_token},
+    solution::{Answer, Part},
+};
+
+/// Expected answers recorded locally, one `<day> <part> <answer>` per line.
+///
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+/// The result of comparing a computed answer against the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, treating a missing file as empty.
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Answers::from_str(&s),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: &Answer) {
+        self.0.insert((day, part), answer.to_string());
+    }
+
+    pub fn verify(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = parse_lines(s, |line| {
+            if line.starts_with('#') {
+                return Ok(None);
+            }
+
+            let mut fields = line.split_ascii_whitespace();
+            let day = parse_token(line, next_field(line, &mut fields, "day")?, "a day")?;
+            let part = parse_token(line, next_field(line, &mut fields, "part")?, "`1` or `2`")?;
+            let answer = next_field(line, &mut fields, "answer")?;
+
+            Ok(Some(((day, part), answer.to_string())))
+        })?;
+
+        Ok(Answers(entries.into_iter().flatten().collect()))
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, part), answer) in &self.0 {
+            writeln!(f, "{day:02} {part} {answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { .. } => write!(f, "fail"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    hint::black_box,
+    io,
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Error,
+    input::{next_field, parse_lines, parse_token},
+    solution::Solution,
+};
+
+/// One separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn all() -> [Phase; 3] {
+        [Phase::Parse, Phase::Part1, Phase::Part2]
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part1),
+            "part2" => Ok(Phase::Part2),
+            _ => Err(Error::invalid_token(s, s, "`parse`, `part1` or `part2`")),
+        }
+    }
+}
+
+/// Summary of repeated timings of a single phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "median {:?} (min {:?}, max {:?})",
+            self.median, self.min, self.max
+        )
+    }
+}
+
+/// Timings of every phase of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    pub fn get(&self, phase: Phase) -> Stats {
+        match phase {
+            Phase::Parse => self.parse,
+            Phase::Part1 => self.part1,
+            Phase::Part2 => self.part2,
+        }
+    }
+}
+
+/// Times each phase of `S` over `runs` runs.
+///
+/// The parts are timed against a single parsed input, so they do not include
+/// the cost of parsing.
+pub fn time<S: Solution>(input: &str, runs: usize) -> Result<Timings, Error> {
+    let runs = runs.max(1);
+    let parsed = S::parse(input)?;
+
+    Ok(Timings {
+        parse: sample(runs, || S::parse(input)),
+        part1: sample(runs, || S::part1(&parsed)),
+        part2: sample(runs, || S::part2(&parsed)),
+    })
+}
+
+fn sample<T>(runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+/// Median timings saved from an earlier run, one
+/// `<day> <phase> <nanoseconds>` per line.
+///
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baselines(BTreeMap<(u8, Phase), Duration>);
+
+impl Baselines {
+    /// Loads the baselines at `path`, treating a missing file as empty.
+    pub fn load(path: impl AsRef<Path>) -> Result<Baselines, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Baselines::from_str(&s),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baselines::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.0.get(&(day, phase)).copied()
+    }
+
+    /// Records the medians of `timings` as the baseline for `day`.
+    pub fn insert(&mut self, day: u8, timings: &Timings) {
+        for phase in Phase::all() {
+            self.0.insert((day, phase), timings.get(phase).median);
+        }
+    }
+
+    /// How much slower `median` is than the baseline, as a percentage, if it
+    /// is more than `threshold` percent slower.
+    pub fn regression(
+        &self,
+        day: u8,
+        phase: Phase,
+        median: Duration,
+        threshold: f64,
+    ) -> Option<f64> {
+        let baseline = self.get(day, phase)?.as_nanos() as f64;
+        if baseline == 0.0 {
+            return None;
+        }
+
+        // Taking the difference first keeps a median exactly `threshold`
+        // percent slower from rounding up past it.
+        let slower = (median.as_nanos() as f64 - baseline) * 100.0 / baseline;
+        (slower > threshold).then_some(slower)
+    }
+}
+
+impl FromStr for Baselines {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = parse_lines(s, |line| {
+            if line.starts_with('#') {
+                return Ok(None);
+            }
+
+            let mut fields = line.split_ascii_whitespace();
+            let day = parse_token(line, next_field(line, &mut fields, "day")?, "a day")?;
+            let phase = parse_token(line, next_field(line, &mut fields, "phase")?, "a phase")?;
+            let nanos = parse_token(
+                line,
+                next_field(line, &mut fields, "duration")?,
+                "a duration in nanoseconds",
+            )?;
+
+            Ok(Some(((day, phase), Duration::from_nanos(nanos))))
+        })?;
+
+        Ok(Baselines(entries.into_iter().flatten().collect()))
+    }
+}
+
+impl Display for Baselines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, phase), duration) in &self.0 {
+            writeln!(f, "{day:02} {phase} {}", duration.as_nanos())?;
+        }
+
+        Ok(())
+    }
+}
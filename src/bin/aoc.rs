@@ -0,0 +1,390 @@
+use std::{env, fs, process};
+
+use adventofcode_2023::{
+    answers::{Answers, Verdict},
+    bench::{Baselines, Phase},
+    days,
+    generate::{self, Rng},
+    report::{self, Format, Report, CSV_HEADER},
+    solution::{self, Answer, Part, RunOptions},
+    Error,
+};
+
+const USAGE: &str =
+    "usage: aoc <day> [--input <path>|-] [--part 1|2] [--format text|json|csv] [--check]
+       aoc all [--part 1|2] [--format text|json|csv] [--check]
+       aoc verify [<day>]
+       aoc record [<day>]
+       aoc bench [<day>] [--runs <n>] [--threshold <percent>] [--save]
+       aoc generate <day> [--size <n>] [--seed <n>]
+
+  aoc <day>     solve a single day, reading --input if given (`-` for
+                stdin), else whatever is piped in, else inputs/NN.txt;
+                --part solves only that part, --format json or csv adds
+                parse and solve times; --check only parses the input and
+                lists every problem with it
+  aoc all       solve every day, reading inputs/NN.txt for each
+  aoc verify    compare answers against those recorded in inputs/answers.txt
+  aoc record    record the current answers in inputs/answers.txt
+  aoc bench     time parsing and each part, flagging phases more than
+                --threshold percent (default 10) slower than the baselines
+                in inputs/baselines.txt; --save records new baselines
+  aoc generate  print a synthetic input for a day, e.g. to pipe into
+                `aoc <day> --input -`; --size scales it (default close to
+                the official input) and --seed picks which one (default 0)";
+
+const ANSWERS_PATH: &str = "inputs/answers.txt";
+const BASELINES_PATH: &str = "inputs/baselines.txt";
+
+/// Options for `aoc bench`.
+struct BenchOptions {
+    days: Vec<u8>,
+    runs: usize,
+    threshold: f64,
+    save: bool,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let ok = match args.as_slice() {
+        ["all", rest @ ..] => match RunOptions::parse(rest) {
+            Some(options) if options.input.is_none() => run_all(&options),
+            _ => {
+                eprintln!("{USAGE}");
+                false
+            }
+        },
+        ["verify", rest @ ..] => selected_days(rest).is_some_and(|days| verify(&days)),
+        ["record", rest @ ..] => selected_days(rest).is_some_and(|days| record(&days)),
+        ["generate", day, rest @ ..] => match (parse_day(day), generate_options(rest)) {
+            (Some(day), Some((size, seed))) => generate(day, size, seed),
+            _ => {
+                eprintln!("{USAGE}");
+                false
+            }
+        },
+        ["bench", rest @ ..] => match bench_options(rest) {
+            Some(options) => bench(&options),
+            None => {
+                eprintln!("{USAGE}");
+                false
+            }
+        },
+        [day, rest @ ..] => match (parse_day(day), RunOptions::parse(rest)) {
+            (Some(day), Some(options)) => run_single(day, &options),
+            _ => {
+                eprintln!("{USAGE}");
+                false
+            }
+        },
+        _ => {
+            eprintln!("{USAGE}");
+            false
+        }
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+/// Parses an optional day argument, defaulting to every day.
+fn selected_days(args: &[&str]) -> Option<Vec<u8>> {
+    match args {
+        [] => Some((1..=days::COUNT).collect()),
+        [day] => match parse_day(day) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("{USAGE}");
+                None
+            }
+        },
+        _ => {
+            eprintln!("{USAGE}");
+            None
+        }
+    }
+}
+
+fn bench_options(mut args: &[&str]) -> Option<BenchOptions> {
+    let mut options = BenchOptions {
+        days: (1..=days::COUNT).collect(),
+        runs: 10,
+        threshold: 10.0,
+        save: false,
+    };
+
+    loop {
+        args = match args {
+            [] => return Some(options),
+            ["--runs", runs, rest @ ..] => {
+                options.runs = runs.parse().ok().filter(|&runs| runs > 0)?;
+                rest
+            }
+            ["--threshold", threshold, rest @ ..] => {
+                options.threshold = threshold.parse().ok()?;
+                rest
+            }
+            ["--save", rest @ ..] => {
+                options.save = true;
+                rest
+            }
+            [day, rest @ ..] => {
+                options.days = vec![parse_day(day)?];
+                rest
+            }
+        };
+    }
+}
+
+/// Parses `[--size <n>] [--seed <n>]`.
+fn generate_options(mut args: &[&str]) -> Option<(Option<usize>, u64)> {
+    let (mut size, mut seed) = (None, 0);
+    loop {
+        args = match args {
+            [] => return Some((size, seed)),
+            ["--size", n, rest @ ..] => {
+                size = Some(n.parse().ok()?);
+                rest
+            }
+            ["--seed", n, rest @ ..] => {
+                seed = n.parse().ok()?;
+                rest
+            }
+            _ => return None,
+        };
+    }
+}
+
+fn parse_day(s: &str) -> Option<u8> {
+    s.parse().ok().filter(|day| (1..=days::COUNT).contains(day))
+}
+
+fn run_single(day: u8, options: &RunOptions) -> bool {
+    let report = match options.read_input(day) {
+        Ok(input) => {
+            let solution = days::get(day).expect("days are validated when parsing arguments");
+            if options.format == Format::Text {
+                solution.report_with(&input, &options.parts(), &mut report::print_part)
+            } else {
+                solution.report(&input, &options.parts())
+            }
+        }
+        Err(err) => Report::failed(day, err.into()),
+    };
+
+    match options.format {
+        Format::Text => report::print_outcome(&report),
+        format => report::print(&report, format),
+    }
+    report.is_ok()
+}
+
+fn run_all(options: &RunOptions) -> bool {
+    let mut ok = true;
+    let mut reports = Vec::new();
+    if options.format == Format::Csv {
+        println!("{CSV_HEADER}");
+    }
+
+    for day in 1..=days::COUNT {
+        let report = match solution::read_day_input(day) {
+            Ok(input) => {
+                let solution = days::get(day).expect("every day up to COUNT has a solution");
+                let report = if options.format == Format::Text {
+                    println!("Day {day:02}");
+                    solution.report_with(&input, &options.parts(), &mut report::print_part)
+                } else {
+                    solution.report(&input, &options.parts())
+                };
+                ok &= report.is_ok();
+                report
+            }
+            Err(err) if options.format == Format::Text => {
+                println!("Day {day:02}: skipped, {err}");
+                continue;
+            }
+            Err(err) => Report::failed(day, err.into()),
+        };
+
+        match options.format {
+            Format::Text => report::print_outcome(&report),
+            Format::Csv => {
+                for row in report.csv_rows() {
+                    println!("{row}");
+                }
+            }
+            Format::Json => reports.push(report),
+        }
+    }
+
+    if options.format == Format::Json {
+        println!("{}", report::json_array(&reports));
+    }
+
+    ok
+}
+
+fn verify(selected: &[u8]) -> bool {
+    let answers = match Answers::load(ANSWERS_PATH) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{ANSWERS_PATH}: {err}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for &day in selected {
+        let Some(input) = read_input(day) else {
+            continue;
+        };
+
+        match solve(day, &input) {
+            Ok((part1, part2)) => {
+                for (part, answer) in [(Part::One, part1), (Part::Two, part2)] {
+                    let verdict = answers.verify(day, part, &answer);
+                    match &verdict {
+                        Verdict::Fail { expected } => {
+                            println!(
+                                "Day {day:02} part {part}: {verdict} (expected {expected}, got {answer})"
+                            );
+                            ok = false;
+                        }
+                        _ => println!("Day {day:02} part {part}: {verdict} ({answer})"),
+                    }
+                }
+            }
+            Err(err) => {
+                println!("Day {day:02}: fail ({err})");
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+fn record(selected: &[u8]) -> bool {
+    let mut answers = match Answers::load(ANSWERS_PATH) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{ANSWERS_PATH}: {err}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for &day in selected {
+        let Some(input) = read_input(day) else {
+            continue;
+        };
+
+        match solve(day, &input) {
+            Ok((part1, part2)) => {
+                for (part, answer) in [(Part::One, part1), (Part::Two, part2)] {
+                    if answer != Answer::Unsolved {
+                        println!("Day {day:02} part {part}: recorded {answer}");
+                        answers.insert(day, part, &answer);
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("day {day}: {err}");
+                ok = false;
+            }
+        }
+    }
+
+    if let Err(err) = answers.save(ANSWERS_PATH) {
+        eprintln!("{ANSWERS_PATH}: {err}");
+        return false;
+    }
+
+    ok
+}
+
+fn bench(options: &BenchOptions) -> bool {
+    let mut baselines = match Baselines::load(BASELINES_PATH) {
+        Ok(baselines) => baselines,
+        Err(err) => {
+            eprintln!("{BASELINES_PATH}: {err}");
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    for &day in &options.days {
+        let Some(input) = read_input(day) else {
+            continue;
+        };
+
+        let solution = days::get(day).expect("days are validated when parsing arguments");
+        let timings = match solution.bench(&input, options.runs) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("day {day}: {err}");
+                ok = false;
+                continue;
+            }
+        };
+
+        for phase in Phase::all() {
+            let stats = timings.get(phase);
+            match baselines.regression(day, phase, stats.median, options.threshold) {
+                Some(slower) => {
+                    println!("Day {day:02} {phase}: {stats}, {slower:.1}% slower than baseline");
+                    ok = false;
+                }
+                None => println!("Day {day:02} {phase}: {stats}"),
+            }
+        }
+
+        if options.save {
+            baselines.insert(day, &timings);
+        }
+    }
+
+    if options.save {
+        if let Err(err) = baselines.save(BASELINES_PATH) {
+            eprintln!("{BASELINES_PATH}: {err}");
+            return false;
+        }
+    }
+
+    ok
+}
+
+fn generate(day: u8, size: Option<usize>, seed: u64) -> bool {
+    let size = size
+        .or_else(|| generate::default_size(day))
+        .expect("days are validated when parsing arguments");
+    let input = generate::generate(day, size, &mut Rng::new(seed))
+        .expect("days are validated when parsing arguments");
+    print!("{input}");
+
+    true
+}
+
+fn solve(day: u8, input: &str) -> Result<(Answer, Answer), Error> {
+    days::get(day)
+        .expect("days are validated when parsing arguments")
+        .run(input)
+}
+
+/// Reads `inputs/NN.txt`, reporting and skipping days without an input.
+fn read_input(day: u8) -> Option<String> {
+    let path = solution::input_path(day);
+    match fs::read_to_string(&path) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            println!(
+                "Day {day:02}: skipped, could not read {}: {err}",
+                path.display()
+            );
+            None
+        }
+    }
+}
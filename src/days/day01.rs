@@ -0,0 +1,92 @@
+use crate::{error::Error, solution::Solution};
+
+const DIGIT_WORDS: &[(&str, u32)] = &[
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input
+            .iter()
+            .map(|line| calibration_value(line, false))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        input.iter().map(|line| calibration_value(line, true)).sum()
+    }
+}
+
+/// The number formed by the first and last digits in `line`, also counting
+/// spelled-out digits like `one` if `include_words` is set.
+pub fn calibration_value(line: &str, include_words: bool) -> u32 {
+    let mut first_char: Option<(usize, u32)> = None;
+    let mut last_char: Option<(usize, u32)> = None;
+
+    let mut found = |i: usize, d: u32| {
+        match first_char {
+            Some((prev_index, _)) => {
+                if i < prev_index {
+                    first_char = Some((i, d));
+                }
+            }
+            None => {
+                first_char = Some((i, d));
+            }
+        }
+
+        match last_char {
+            Some((prev_index, _)) => {
+                if i > prev_index {
+                    last_char = Some((i, d));
+                }
+            }
+            None => {
+                last_char = Some((i, d));
+            }
+        }
+    };
+
+    for (i, c) in line.char_indices() {
+        if let Some(d) = c.to_digit(10) {
+            found(i, d);
+        }
+    }
+
+    if include_words {
+        for i in 0..line.len() {
+            for &(digit_str, d) in DIGIT_WORDS {
+                if line.get(i..(i + digit_str.len())) == Some(digit_str) {
+                    found(i, d);
+                }
+            }
+        }
+    }
+
+    first_char.unwrap_or_default().1 * 10 + last_char.unwrap_or_default().1
+}
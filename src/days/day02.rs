@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::Error,
+    input::{next_field, parse_lines, parse_token},
+    solution::Solution,
+};
+
+pub struct Day02;
+
+pub struct Game {
+    pub id: u32,
+    /// The most cubes of each color shown in any single round.
+    pub maxes: HashMap<String, u32>,
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            let (prelude, rounds) = line
+                .split_once(": ")
+                .ok_or_else(|| Error::missing_field(line, "game rounds"))?;
+            let id_str = prelude
+                .strip_prefix("Game ")
+                .ok_or_else(|| Error::invalid_token(line, prelude, "`Game <id>`"))?;
+            let id: u32 = parse_token(line, id_str, "a game id")?;
+
+            let mut maxes: HashMap<String, u32> = HashMap::new();
+            for round in rounds.trim().split("; ") {
+                for cube in round.split(", ") {
+                    let mut words = cube.split_ascii_whitespace();
+                    let count: u32 =
+                        parse_token(line, next_field(line, &mut words, "cube count")?, "a count")?;
+                    let color = next_field(line, &mut words, "cube color")?;
+
+                    let max = maxes.entry(color.to_string()).or_default();
+                    *max = (*max).max(count);
+                }
+            }
+
+            Ok(Game { id, maxes })
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let mut limits: HashMap<&str, u32> = HashMap::new();
+        limits.insert("red", 12);
+        limits.insert("green", 13);
+        limits.insert("blue", 14);
+
+        input
+            .iter()
+            .filter(|game| {
+                game.maxes
+                    .iter()
+                    .all(|(color, count)| match limits.get(color.as_str()) {
+                        Some(max) => *count <= *max,
+                        None => false,
+                    })
+            })
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        input
+            .iter()
+            .map(|game| game.maxes.values().product::<u32>())
+            .sum()
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::{Error, Errors, Location},
+    solution::Solution,
+};
+
+pub struct Day03;
+
+/// A column and row in the schematic.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Point(pub i32, pub i32);
+
+/// A number in the schematic, spanning `start` to `end` inclusive on one row.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct SchematicNumber {
+    pub value: u32,
+    pub start: Point,
+    pub end: Point,
+}
+
+pub struct Schematic {
+    pub nums: Vec<SchematicNumber>,
+    /// Every character other than digits and `.`, by position.
+    pub symbols: HashMap<Point, char>,
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut nums: Vec<SchematicNumber> = Vec::new();
+        let mut symbols: HashMap<Point, char> = HashMap::new();
+        let mut errors = Errors::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let mut buf = String::new();
+            let mut start: Option<Point> = None;
+            let mut max_x = 0;
+            for (x, c) in line.chars().enumerate() {
+                max_x = x;
+                if c.is_ascii_digit() {
+                    if buf.is_empty() {
+                        start = Some(Point(x as _, y as _));
+                    }
+                    buf.push(c);
+                } else {
+                    if let Some(s) = start {
+                        if let Some(value) = errors.check(parse_part_number(&buf, &s)) {
+                            nums.push(SchematicNumber {
+                                value,
+                                start: s,
+                                end: Point(x as i32 - 1, y as _),
+                            });
+                        }
+                        buf.clear();
+                        start = None;
+                    }
+                    if c != '.' {
+                        symbols.insert(Point(x as _, y as _), c);
+                    }
+                }
+            }
+
+            if let Some(s) = start {
+                if let Some(value) = errors.check(parse_part_number(&buf, &s)) {
+                    nums.push(SchematicNumber {
+                        value,
+                        start: s,
+                        end: Point(max_x as _, y as _),
+                    });
+                }
+            }
+        }
+
+        errors.finish(Schematic { nums, symbols })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let mut part1 = 0;
+        for num in &input.nums {
+            'search: for y in (num.start.1 - 1)..=(num.end.1 + 1) {
+                for x in (num.start.0 - 1)..=(num.end.0 + 1) {
+                    if input.symbols.contains_key(&Point(x, y)) {
+                        part1 += num.value;
+                        break 'search;
+                    }
+                }
+            }
+        }
+
+        part1
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        let mut num_pos: HashMap<Point, &SchematicNumber> = HashMap::new();
+        for num in &input.nums {
+            for x in num.start.0..=num.end.0 {
+                num_pos.insert(Point(x, num.start.1), num);
+            }
+        }
+
+        let mut part2 = 0;
+        for (point, symbol) in &input.symbols {
+            if *symbol == '*' {
+                let mut adj: HashSet<&SchematicNumber> = HashSet::new();
+                for y in (point.1 - 1)..=(point.1 + 1) {
+                    for x in (point.0 - 1)..=(point.0 + 1) {
+                        if let Some(num) = num_pos.get(&Point(x, y)) {
+                            adj.insert(num);
+                        }
+                    }
+                }
+
+                if adj.len() == 2 {
+                    part2 += adj.into_iter().fold(1, |acc, x| acc * x.value);
+                }
+            }
+        }
+
+        part2
+    }
+}
+
+fn parse_part_number(digits: &str, start: &Point) -> Result<u32, Error> {
+    digits.parse().map_err(|_| Error::OutOfRange {
+        value: digits.to_string(),
+        expected: "a 32-bit part number",
+        location: Location::new(start.1 as usize + 1, start.0 as usize + 1),
+    })
+}
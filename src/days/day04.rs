@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::Error,
+    input::{parse_lines, parse_token},
+    solution::Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    /// The number of winning numbers on each card.
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            let (_, numbers) = line
+                .split_once(": ")
+                .ok_or_else(|| Error::missing_field(line, "card numbers"))?;
+            let (winning, yours) = numbers
+                .split_once(" | ")
+                .ok_or_else(|| Error::missing_field(line, "`|` separator"))?;
+
+            let winning: HashSet<u32> = winning
+                .split_ascii_whitespace()
+                .map(|n| parse_token(line, n, "a number"))
+                .collect::<Result<_, _>>()?;
+
+            let mut num_matches = 0;
+            for your_num in yours.split_ascii_whitespace() {
+                if winning.contains(&parse_token(line, your_num, "a number")?) {
+                    num_matches += 1;
+                }
+            }
+
+            Ok(num_matches)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input
+            .iter()
+            .filter(|num_matches| **num_matches > 0)
+            .map(|num_matches| 2_u32.pow(num_matches - 1))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        let mut card_counts: Vec<u32> = vec![1; input.len()];
+        for (i, num_matches) in input.iter().enumerate() {
+            let num_cards = card_counts[i];
+            let won = (i + 1)..=(i + *num_matches as usize).min(input.len() - 1);
+            for count in &mut card_counts[won] {
+                *count += num_cards;
+            }
+        }
+
+        card_counts.into_iter().sum()
+    }
+}
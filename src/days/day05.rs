@@ -0,0 +1,209 @@
+use std::ops;
+
+use crate::{
+    error::{Error, Errors},
+    input::{next_field, parse_token},
+    solution::Solution,
+};
+
+pub struct Day05;
+
+/// A half-open range of seed, soil, location, etc. numbers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueRange(pub ops::Range<u64>);
+
+impl ValueRange {
+    pub fn new(start: u64, end: u64) -> ValueRange {
+        ValueRange(start..end)
+    }
+
+    /// Splits this range by `other`, returning the overlapping part, if any,
+    /// and the pieces of `self` that lie outside `other`.
+    pub fn intersection(&self, other: &ValueRange) -> (Option<ValueRange>, Vec<ValueRange>) {
+        if self.0.start < other.0.end && self.0.end > other.0.start {
+            let intersection =
+                ValueRange(self.0.start.max(other.0.start)..self.0.end.min(other.0.end));
+            let mut rem = Vec::new();
+
+            if self.0.start < other.0.start {
+                rem.push(ValueRange(self.0.start..other.0.start));
+            }
+
+            if self.0.end > other.0.end {
+                rem.push(ValueRange(other.0.end..self.0.end));
+            }
+
+            return (Some(intersection), rem);
+        }
+
+        (None, vec![self.clone()])
+    }
+}
+
+/// One line of a map: `range_len` values starting at `source_start` are
+/// mapped to the same number of values starting at `dest_start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapEntry {
+    pub dest_start: u64,
+    pub source_start: u64,
+    pub range_len: u64,
+}
+
+impl MapEntry {
+    pub fn new(dest_start: u64, source_start: u64, range_len: u64) -> MapEntry {
+        MapEntry {
+            dest_start,
+            source_start,
+            range_len,
+        }
+    }
+
+    /// The range of source values this entry covers.
+    pub fn source(&self) -> ValueRange {
+        ValueRange::new(self.source_start, self.source_start + self.range_len)
+    }
+
+    /// Where `value` is sent, if this entry covers it.
+    pub fn map(&self, value: u64) -> Option<u64> {
+        (self.source_start..self.source_start + self.range_len)
+            .contains(&value)
+            .then(|| value - self.source_start + self.dest_start)
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// Each map in order, from seed-to-soil through humidity-to-location.
+    pub maps: Vec<Vec<MapEntry>>,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut seeds: Vec<u64> = Vec::new();
+        let mut maps: Vec<Vec<MapEntry>> = Vec::new();
+        let mut errors = Errors::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.ends_with("map:") {
+                maps.push(Vec::new());
+                continue;
+            }
+
+            let on_line = |err: Error| err.on_line(i + 1);
+
+            if let Some(seeds_str) = line.strip_prefix("seeds:") {
+                seeds = seeds_str
+                    .split_ascii_whitespace()
+                    .filter_map(|s| {
+                        errors.check(parse_token(line, s, "a seed number").map_err(on_line))
+                    })
+                    .collect();
+                continue;
+            }
+
+            let Some(entry) = errors.check(parse_entry(line).map_err(on_line)) else {
+                continue;
+            };
+            match maps.last_mut() {
+                Some(map) => map.push(entry),
+                None => errors.push(on_line(Error::invalid_token(
+                    line,
+                    line,
+                    "a `<name> map:` header before its entries",
+                ))),
+            }
+        }
+
+        errors.finish(Almanac { seeds, maps })
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        let mut working_vals = input.seeds.clone();
+        let mut next_vals: Vec<u64> = Vec::new();
+
+        for map in &input.maps {
+            for entry in map {
+                working_vals.retain(|&val| match entry.map(val) {
+                    Some(next) => {
+                        next_vals.push(next);
+                        false
+                    }
+                    None => true,
+                });
+            }
+
+            working_vals.append(&mut next_vals);
+        }
+
+        working_vals.into_iter().min().unwrap_or_default()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        let mut working_ranges: Vec<ValueRange> = input
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| ValueRange::new(pair[0], pair[0] + pair[1]))
+            .collect();
+        let mut next_ranges: Vec<ValueRange> = Vec::new();
+
+        for map in &input.maps {
+            for entry in map {
+                let source_start = entry.source_start;
+                let dest_start = entry.dest_start;
+
+                // not good but good enough
+                working_ranges = working_ranges
+                    .into_iter()
+                    .flat_map(|working_range| {
+                        let (intersection, rem) = working_range.intersection(&entry.source());
+
+                        if let Some(intersection) = intersection {
+                            if dest_start > source_start {
+                                let diff = dest_start - source_start;
+                                next_ranges.push(ValueRange(
+                                    (intersection.0.start + diff)..(intersection.0.end + diff),
+                                ))
+                            } else {
+                                let diff = source_start - dest_start;
+                                next_ranges.push(ValueRange(
+                                    (intersection.0.start - diff)..(intersection.0.end - diff),
+                                ))
+                            }
+                        }
+
+                        rem
+                    })
+                    .collect();
+            }
+
+            working_ranges.append(&mut next_ranges);
+        }
+
+        working_ranges
+            .iter()
+            .map(|range| range.0.start)
+            .min()
+            .unwrap_or_default()
+    }
+}
+
+fn parse_entry(line: &str) -> Result<MapEntry, Error> {
+    let mut nums = line.split_ascii_whitespace();
+    let mut next = |field| parse_token(line, next_field(line, &mut nums, field)?, "a number");
+
+    Ok(MapEntry::new(
+        next("destination range start")?,
+        next("source range start")?,
+        next("range length")?,
+    ))
+}
@@ -0,0 +1,79 @@
+use crate::{
+    error::Error,
+    input::{parse_lines, parse_token},
+    solution::Solution,
+};
+
+pub struct Day06;
+
+pub struct Races {
+    pub times: Vec<u64>,
+    pub records: Vec<u64>,
+    /// The single race read by ignoring the spaces between numbers.
+    pub long_time: u64,
+    pub long_record: u64,
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut lines = parse_lines(input, |line| {
+            let (_, values_str) = line
+                .split_once(':')
+                .ok_or_else(|| Error::missing_field(line, "`:` separator"))?;
+            let values = values_str
+                .split_ascii_whitespace()
+                .map(|s| parse_token(line, s, "a number"))
+                .collect::<Result<Vec<u64>, _>>()?;
+            let long_value = values_str
+                .replace(' ', "")
+                .parse::<u64>()
+                .map_err(|_| Error::out_of_range(line, values_str.trim(), "a 64-bit number"))?;
+
+            Ok((values, long_value))
+        })?
+        .into_iter();
+        let (times, long_time) = lines
+            .next()
+            .ok_or_else(|| Error::missing_field(input, "race times"))?;
+        let (records, long_record) = lines
+            .next()
+            .ok_or_else(|| Error::missing_field(input, "record distances"))?;
+
+        Ok(Races {
+            times,
+            records,
+            long_time,
+            long_record,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        input
+            .times
+            .iter()
+            .zip(&input.records)
+            .map(|(&time, &record_dist)| simulate_records(time, record_dist))
+            .product()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        simulate_records(input.long_time, input.long_record)
+    }
+}
+
+/// How many ways there are to beat `record_dist` in a race lasting `time`.
+pub fn simulate_records(time: u64, record_dist: u64) -> u64 {
+    (0..time).fold(0, |acc, t| {
+        if t * (time - t) > record_dist {
+            acc + 1
+        } else {
+            acc
+        }
+    })
+}
@@ -0,0 +1,239 @@
+use std::{cmp, collections::HashMap, str::FromStr};
+
+use crate::{
+    error::{Error, Location},
+    input::{next_field, parse_lines, parse_token},
+    solution::Solution,
+};
+
+pub struct Day07;
+
+/// A playing card, ordered by strength. `Joker` only appears after
+/// [`Hand::jokerize`] and is weaker than every other card.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Debug)]
+pub enum Card {
+    Joker,
+    Numeric2,
+    Numeric3,
+    Numeric4,
+    Numeric5,
+    Numeric6,
+    Numeric7,
+    Numeric8,
+    Numeric9,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'A' => Ok(Card::A),
+            'K' => Ok(Card::K),
+            'Q' => Ok(Card::Q),
+            'J' => Ok(Card::J),
+            'T' => Ok(Card::T),
+            '9' => Ok(Card::Numeric9),
+            '8' => Ok(Card::Numeric8),
+            '7' => Ok(Card::Numeric7),
+            '6' => Ok(Card::Numeric6),
+            '5' => Ok(Card::Numeric5),
+            '4' => Ok(Card::Numeric4),
+            '3' => Ok(Card::Numeric3),
+            '2' => Ok(Card::Numeric2),
+            _ => Err(Error::InvalidToken {
+                token: value.to_string(),
+                expected: "a card",
+                location: Location::new(1, 1),
+            }),
+        }
+    }
+}
+
+/// The type of a hand, from weakest to strongest.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Debug)]
+pub enum HandResult {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// A hand of five cards, ordered first by its type and then card by card.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Hand {
+    cards: [Card; 5],
+}
+
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards: Vec<Card> = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Card::try_from(c).map_err(|err| err.offset(Location::new(1, i + 1))))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            cards: cards
+                .try_into()
+                .map_err(|_| Error::invalid_token(s, s, "a hand of 5 cards"))?,
+        })
+    }
+}
+
+impl Hand {
+    pub fn new(cards: [Card; 5]) -> Hand {
+        Hand { cards }
+    }
+
+    pub fn cards(&self) -> &[Card; 5] {
+        &self.cards
+    }
+
+    /// Turns every `J` into a joker, which is wild when determining the hand's
+    /// type but the weakest card when breaking ties.
+    pub fn jokerize(&mut self) {
+        for card in &mut self.cards {
+            if *card == Card::J {
+                *card = Card::Joker;
+            }
+        }
+    }
+
+    /// The strongest type this hand can make, counting jokers as wild.
+    pub fn result(&self) -> HandResult {
+        let mut counts: HashMap<&Card, u64> = HashMap::new();
+        for card in &self.cards {
+            *counts.entry(card).or_default() += 1;
+        }
+
+        match counts.len() {
+            1 => HandResult::FiveOfAKind,
+            2 => {
+                if counts.values().any(|count| *count == 4) {
+                    if counts.contains_key(&Card::Joker) {
+                        HandResult::FiveOfAKind
+                    } else {
+                        HandResult::FourOfAKind
+                    }
+                } else {
+                    match counts.get(&Card::Joker) {
+                        Some(2 | 3) => HandResult::FiveOfAKind,
+                        Some(1) => HandResult::FourOfAKind,
+                        _ => HandResult::FullHouse,
+                    }
+                }
+            }
+            3 => {
+                if counts.values().any(|count| *count == 3) {
+                    if counts.contains_key(&Card::Joker) {
+                        HandResult::FourOfAKind
+                    } else {
+                        HandResult::ThreeOfAKind
+                    }
+                } else {
+                    match counts.get(&Card::Joker) {
+                        Some(2) => HandResult::FourOfAKind,
+                        Some(1) => HandResult::FullHouse,
+                        _ => HandResult::TwoPair,
+                    }
+                }
+            }
+            4 => {
+                if counts.contains_key(&Card::Joker) {
+                    HandResult::ThreeOfAKind
+                } else {
+                    HandResult::OnePair
+                }
+            }
+            5 => {
+                if counts.contains_key(&Card::Joker) {
+                    HandResult::OnePair
+                } else {
+                    HandResult::HighCard
+                }
+            }
+            _ => panic!("invalid hand: {:?}", self.cards),
+        }
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match self.result().cmp(&other.result()) {
+            cmp::Ordering::Equal => {
+                for i in 0..5 {
+                    if self.cards[i] < other.cards[i] {
+                        return cmp::Ordering::Less;
+                    }
+                    if self.cards[i] > other.cards[i] {
+                        return cmp::Ordering::Greater;
+                    }
+                }
+
+                cmp::Ordering::Equal
+            }
+            o => o,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(Hand, u64)>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            let mut chunks = line.split_ascii_whitespace();
+            let hand_str = next_field(line, &mut chunks, "hand")?;
+            let hand =
+                Hand::from_str(hand_str).map_err(|err| err.offset(Location::of(line, hand_str)))?;
+            let bet = parse_token(line, next_field(line, &mut chunks, "bet")?, "a bet")?;
+            Ok((hand, bet))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        total_winnings(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        let mut hands_and_bets = input.clone();
+        for hand_and_bet in &mut hands_and_bets {
+            hand_and_bet.0.jokerize();
+        }
+
+        total_winnings(hands_and_bets)
+    }
+}
+
+/// The sum of each hand's bet multiplied by its rank, the weakest hand having
+/// rank 1.
+pub fn total_winnings(mut hands_and_bets: Vec<(Hand, u64)>) -> u64 {
+    hands_and_bets.sort_by(|a, b| a.0.cmp(&b.0));
+    hands_and_bets
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, hand_and_bet)| {
+            acc + hand_and_bet.1 * (i as u64 + 1)
+        })
+}
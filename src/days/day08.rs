@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::{Error, Errors, Location},
+    input::parse_lines,
+    solution::Solution,
+};
+
+pub struct Day08;
+
+/// Which of a node's two exits to take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Error;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            c => Err(Error::InvalidToken {
+                token: c.to_string(),
+                expected: "`L` or `R`",
+                location: Location::new(1, 1),
+            }),
+        }
+    }
+}
+
+/// A list of left/right instructions and the nodes they navigate.
+pub struct Network {
+    pub directions: Vec<Direction>,
+    /// Each node's left and right neighbors.
+    pub map: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    /// The node reached by following `dir` from `pos`, or `None` if `pos` is
+    /// not a node in the network.
+    pub fn next<'a>(&'a self, pos: &str, dir: Direction) -> Option<&'a str> {
+        let options = self.map.get(pos)?;
+        if dir == Direction::Left {
+            Some(&options.0)
+        } else {
+            Some(&options.1)
+        }
+    }
+
+    /// The most steps a walk can take before it must be going round in
+    /// circles, having been at every node at every point in the directions.
+    fn max_steps(&self) -> usize {
+        self.map.len() * self.directions.len()
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Part1 = Option<u64>;
+    type Part2 = Option<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut errors = Errors::new();
+        let (directions_str, nodes) = input.split_once('\n').unwrap_or((input, ""));
+        let directions: Vec<Direction> = directions_str
+            .chars()
+            .enumerate()
+            .filter_map(|(i, c)| {
+                errors.check(
+                    Direction::try_from(c).map_err(|err| err.offset(Location::new(1, i + 1))),
+                )
+            })
+            .collect();
+        if directions_str.is_empty() {
+            errors.push(Error::missing_field(
+                directions_str,
+                "left/right directions",
+            ));
+        }
+
+        let keys: HashSet<&str> = nodes
+            .lines()
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(key, _)| key)
+            .collect();
+        let map = parse_lines(nodes, |line| {
+            let (key, dests) = line
+                .split_once(" = ")
+                .ok_or_else(|| Error::missing_field(line, "` = ` separator"))?;
+            let (left, right) = dests
+                .strip_prefix('(')
+                .and_then(|dests| dests.strip_suffix(')'))
+                .and_then(|dests| dests.split_once(", "))
+                .ok_or_else(|| Error::invalid_token(line, dests, "`(<left>, <right>)`"))?;
+
+            let mut unknown = Errors::new();
+            for dest in [left, right] {
+                if !keys.contains(dest) {
+                    unknown.push(Error::invalid_token(line, dest, "a node in the network"));
+                }
+            }
+
+            unknown.finish((key.to_string(), (left.to_string(), right.to_string())))
+        })
+        .map_err(|err| err.offset(Location::new(2, 1)));
+        let map: HashMap<String, (String, String)> =
+            errors.check(map).unwrap_or_default().into_iter().collect();
+
+        errors.finish(Network { directions, map })
+    }
+
+    fn part1(input: &Self::Input) -> Option<u64> {
+        let mut pos = "AAA";
+        let dirs = input.directions.iter().cycle();
+        for (step, &dir) in dirs.enumerate().take(input.max_steps() + 1) {
+            if pos == "ZZZ" {
+                return Some(step as u64);
+            }
+            pos = input.next(pos, dir)?;
+        }
+
+        None
+    }
+
+    fn part2(input: &Self::Input) -> Option<u64> {
+        let mut ghost_positions: Vec<&str> = input
+            .map
+            .keys()
+            .filter(|pos| pos.ends_with('A'))
+            .map(|s| s.as_str())
+            .collect();
+        if ghost_positions.is_empty() {
+            return None;
+        }
+
+        let mut seen: Vec<HashMap<&str, u64>> = vec![HashMap::new(); ghost_positions.len()];
+        let mut cycles: Vec<Option<u64>> = vec![None; ghost_positions.len()];
+        // A ghost that keeps landing on a `Z` node does so again within one
+        // trip round its circle after it starts going round.
+        let dirs = input.directions.iter().cycle();
+        for (step, &dir) in dirs.enumerate().take(2 * input.max_steps()) {
+            if ghost_positions.iter().all(|pos| pos.ends_with('Z')) {
+                return Some(step as u64);
+            }
+            if cycles.iter().all(Option::is_some) {
+                break;
+            }
+
+            let step = step as u64;
+            for (i, pos) in ghost_positions.iter_mut().enumerate() {
+                *pos = input.next(pos, dir)?;
+
+                if cycles[i].is_none() && pos.ends_with('Z') {
+                    if let Some(first_seen) = seen[i].get(pos) {
+                        cycles[i] = Some(step - first_seen);
+                    } else {
+                        seen[i].insert(pos, step);
+                    }
+                }
+            }
+        }
+
+        let cycles: Option<Vec<u64>> = cycles.into_iter().collect();
+        Some(cycles?.into_iter().fold(1, lcm))
+    }
+}
+
+/// The least common multiple of `a` and `b`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    a * (b / gcd(a, b))
+}
+
+/// The greatest common divisor of `a` and `b`.
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else if b > a {
+        gcd(b, a)
+    } else {
+        gcd(b, a % b)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    error::Error,
+    input::{parse_lines, parse_token},
+    solution::Solution,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            line.split_ascii_whitespace()
+                .map(|s| parse_token(line, s, "a number"))
+                .collect()
+        })
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        input.iter().map(|history| extrapolate(history).1).sum()
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        input.iter().map(|history| extrapolate(history).0).sum()
+    }
+}
+
+/// Returns the extrapolated values before and after the history.
+pub fn extrapolate(history: &[i64]) -> (i64, i64) {
+    let mut nums: Vec<Vec<i64>> = vec![history.to_vec()];
+
+    while !nums.last().unwrap().iter().all(|n| *n == 0) {
+        let last = nums.last().unwrap();
+        nums.push(
+            (0..(last.len() - 1))
+                .map(|i| last[i + 1] - last[i])
+                .collect(),
+        );
+    }
+
+    for i in (1..nums.len()).rev() {
+        let new_beginning = nums[i - 1].first().unwrap() - nums[i].first().unwrap();
+        // shifting here isn't performant but it's fast enough to not matter
+        nums[i - 1].insert(0, new_beginning);
+        let new_end = nums[i - 1].last().unwrap() + nums[i].last().unwrap();
+        nums[i - 1].push(new_end);
+    }
+
+    (*nums[0].first().unwrap(), *nums[0].last().unwrap())
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use crate::{error::Error, grid::*, input::invalid_tile, polygon::Polygon, solution::Solution};
+
+pub struct Day10;
+
+pub struct PipeMap {
+    pub grid: Grid<char>,
+    /// Where the `S` is.
+    pub start: Position,
+    /// The loop running through the `S`.
+    pub pipe_loop: PipeLoop,
+    /// The area the loop encloses.
+    pub pipes: Polygon,
+}
+
+/// The loop of pipes running through the start tile.
+pub struct PipeLoop {
+    /// Each tile in the loop and how many steps along it it is from the start.
+    pub positions: HashMap<Position, usize>,
+    /// The pipe hidden under the `S`.
+    pub start_tile: char,
+    pub len: usize,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = PipeMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (grid, [starts]) = Grid::parse_with_markers(input, [('S', 'S')], |c| match c {
+            '.' => Ok(c),
+            c if tile_ends(c).is_some() => Ok(c),
+            c => Err(invalid_tile(c, "a pipe tile")),
+        })?;
+
+        let start = *starts
+            .first()
+            .ok_or_else(|| Error::missing_field(input, "start position `S`"))?;
+        let not_on_a_loop = || {
+            let s = input.find('S').unwrap_or_default();
+            Error::invalid_token(input, &input[s..s + 1], "a start on a loop of pipes")
+        };
+        let pipe_loop = find_loop(&grid, start).ok_or_else(not_on_a_loop)?;
+
+        let mut path: Vec<_> = pipe_loop.positions.iter().collect();
+        path.sort_unstable_by_key(|(_, &step)| step);
+        let vertices: Vec<_> = path.iter().map(|(pos, _)| pos.to_signed()).collect();
+        let pipes = Polygon::new(vertices.iter().chain(vertices.first()).copied())
+            .map_err(|_| not_on_a_loop())?;
+
+        Ok(PipeMap {
+            grid,
+            start,
+            pipe_loop,
+            pipes,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let step = input.pipe_loop.len;
+        step / 2 + step % 2
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.pipes.interior_points() as usize
+    }
+}
+
+/// Follows the pipes out of `start` until they return to it, or `None` if
+/// they don't: if no pipe connects to `start`, or one leads off the grid or
+/// into a tile that doesn't connect back.
+pub fn find_loop(grid: &Grid<char>, start: Position) -> Option<PipeLoop> {
+    // Whether the tile at `to` has a pipe leading back to `from`.
+    let connects = |from: Position, to: Position| {
+        grid.get_pos(to)
+            .and_then(|&tile| tile_ends(tile))
+            .is_some_and(|vectors| vectors.into_iter().any(|vector| to + vector == Ok(from)))
+    };
+
+    let mut pipe_loop: HashMap<Position, usize> = HashMap::new();
+    pipe_loop.insert(start, 0);
+
+    let mut prev = start;
+    let (_, mut pos, _) = grid
+        .neighbors4(start)
+        .find(|&(_, pos, _)| connects(start, pos))?;
+    let mut start_vectors = vec![pos - start];
+
+    let mut step = 1;
+    pipe_loop.insert(pos, step);
+    while pos != start {
+        step += 1;
+
+        let next_pos = tile_ends(grid[pos])?
+            .into_iter()
+            .filter_map(|vector| (pos + vector).ok())
+            .find(|next_pos| *next_pos != prev)?;
+        if next_pos != start && !connects(pos, next_pos) {
+            return None;
+        }
+        prev = pos;
+        pos = next_pos;
+
+        // The start keeps its step of 0 when the loop comes back round to it.
+        pipe_loop.entry(pos).or_insert(step);
+    }
+    start_vectors.push(prev - pos);
+
+    let start_tile = ['|', '-', 'L', 'J', '7', 'F']
+        .into_iter()
+        .find(|possible_tile| {
+            let vectors = tile_ends(*possible_tile).unwrap();
+            vectors.into_iter().all(|v| start_vectors.contains(&v))
+        })?;
+
+    Some(PipeLoop {
+        positions: pipe_loop,
+        start_tile,
+        len: step,
+    })
+}
+
+/// The directions a pipe tile connects to, or `None` if it is not a pipe.
+pub fn tile_ends(tile: char) -> Option<[Vector; 2]> {
+    match tile {
+        '|' => Some([Vector(0, -1), Vector(0, 1)]),
+        '-' => Some([Vector(-1, 0), Vector(1, 0)]),
+        'L' => Some([Vector(0, -1), Vector(1, 0)]),
+        'J' => Some([Vector(0, -1), Vector(-1, 0)]),
+        '7' => Some([Vector(-1, 0), Vector(0, 1)]),
+        'F' => Some([Vector(0, 1), Vector(1, 0)]),
+        _ => None,
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use crate::{error::Error, grid::*, input::invalid_tile, solution::Solution};
+
+pub struct Day11;
+
+pub struct Image {
+    pub grid: Grid<char>,
+    pub galaxies: Vec<Position>,
+    pub cols_with_galaxies: HashSet<usize>,
+    pub rows_with_galaxies: HashSet<usize>,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Image;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (grid, [galaxies]) = Grid::parse_with_markers(input, [('#', '#')], |c| match c {
+            '.' => Ok(c),
+            c => Err(invalid_tile(c, "`#` or `.`")),
+        })?;
+        let cols_with_galaxies = galaxies.iter().map(|galaxy| galaxy.0).collect();
+        let rows_with_galaxies = galaxies.iter().map(|galaxy| galaxy.1).collect();
+
+        Ok(Image {
+            grid,
+            galaxies,
+            cols_with_galaxies,
+            rows_with_galaxies,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u128 {
+        total_distance(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> u128 {
+        total_distance(input, 1_000_000)
+    }
+}
+
+/// The sum of the distances between every pair of galaxies, after each empty
+/// row and column has grown to `expansion` rows or columns.
+pub fn total_distance(image: &Image, expansion: u128) -> u128 {
+    let galaxies = &image.galaxies;
+    let cols_to_expand: HashSet<usize> = (0..image.grid.width())
+        .filter(|i| !image.cols_with_galaxies.contains(i))
+        .collect();
+    let rows_to_expand: HashSet<usize> = (0..image.grid.height())
+        .filter(|i| !image.rows_with_galaxies.contains(i))
+        .collect();
+
+    let mut total: u128 = 0;
+    for i in 0..galaxies.len() {
+        for j in i..galaxies.len() {
+            for x in galaxies[i].0.min(galaxies[j].0)..galaxies[i].0.max(galaxies[j].0) {
+                if cols_to_expand.contains(&x) {
+                    total += expansion;
+                } else {
+                    total += 1;
+                }
+            }
+
+            for y in galaxies[i].1.min(galaxies[j].1)..galaxies[i].1.max(galaxies[j].1) {
+                if rows_to_expand.contains(&y) {
+                    total += expansion;
+                } else {
+                    total += 1;
+                }
+            }
+        }
+    }
+
+    total
+}
@@ -0,0 +1,179 @@
+use std::iter;
+
+use crate::{
+    error::{Error, Location},
+    input::{next_field, parse_lines, parse_token},
+    solution::Solution,
+};
+
+pub struct Day12;
+
+/// The condition of a single spring, which may not be known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            c => Err(Error::InvalidToken {
+                token: c.to_string(),
+                expected: "`.`, `#` or `?`",
+                location: Location::new(1, 1),
+            }),
+        }
+    }
+}
+
+/// One row of springs and the sizes of its contiguous groups of damaged
+/// springs.
+pub struct Record {
+    pub springs: Vec<Spring>,
+    pub sizes: Vec<u32>,
+}
+
+impl Record {
+    pub fn new(springs: Vec<Spring>, sizes: Vec<u32>) -> Record {
+        Record { springs, sizes }
+    }
+
+    /// How many ways the unknown springs could be filled in to match the
+    /// group sizes.
+    pub fn arrangements(&self) -> u64 {
+        count_arrangements(&self.springs, &self.sizes)
+    }
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Record>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, |line| {
+            let mut pieces = line.split_ascii_whitespace();
+            let springs: Vec<Spring> = next_field(line, &mut pieces, "springs")?
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    Spring::try_from(c).map_err(|err| err.offset(Location::new(1, i + 1)))
+                })
+                .collect::<Result<_, _>>()?;
+            let sizes: Vec<u32> = next_field(line, &mut pieces, "group sizes")?
+                .split(',')
+                .map(|s| parse_token(line, s, "a group size"))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Record::new(springs, sizes))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        input.iter().map(Record::arrangements).sum()
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        input
+            .iter()
+            .map(|record| {
+                let len = ((record.springs.len() + 1) * 5) - 1;
+                let springs: Vec<_> = record
+                    .springs
+                    .iter()
+                    .copied()
+                    .chain(iter::once(Spring::Unknown))
+                    .cycle()
+                    .take(len)
+                    .collect();
+                let len = record.sizes.len() * 5;
+                let sizes: Vec<_> = record.sizes.iter().copied().cycle().take(len).collect();
+
+                count_arrangements(&springs, &sizes)
+            })
+            .sum()
+    }
+}
+
+/// How many ways the unknown springs could be filled in so that the damaged
+/// groups have exactly `sizes`.
+pub fn count_arrangements(springs: &[Spring], sizes: &[u32]) -> u64 {
+    let unknowns: Vec<_> = springs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, s)| if *s == Spring::Unknown { Some(i) } else { None })
+        .collect();
+
+    let mut count = 0;
+    for n in 0..2u128.pow(unknowns.len() as u32) {
+        let mut m = n;
+        let mut potential_springs = springs.to_vec();
+        for i in &unknowns {
+            potential_springs[*i] = if m & 1 == 1 {
+                Spring::Damaged
+            } else {
+                Spring::Operational
+            };
+            m >>= 1;
+        }
+
+        if check_springs(&potential_springs, sizes) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn check_springs(springs: &[Spring], sizes: &[u32]) -> bool {
+    let mut size_i = 0;
+
+    let mut damaged_size = 0;
+    for spring in springs {
+        match spring {
+            Spring::Unknown => {
+                return false;
+            }
+            Spring::Damaged => {
+                damaged_size += 1;
+            }
+            Spring::Operational => {
+                if damaged_size > 0 {
+                    if let Some(current_size) = sizes.get(size_i) {
+                        if *current_size != damaged_size {
+                            return false;
+                        }
+                    } else {
+                        return false;
+                    }
+
+                    size_i += 1;
+                    damaged_size = 0;
+                }
+            }
+        }
+    }
+
+    if damaged_size > 0 {
+        if let Some(current_size) = sizes.get(size_i) {
+            if *current_size != damaged_size {
+                return false;
+            }
+        } else {
+            return false;
+        }
+
+        size_i += 1;
+    }
+
+    size_i == sizes.len()
+}
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::{Error, Errors, Location},
+    grid::{Axis, Grid, Position},
+    input::invalid_tile,
+    solution::Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Grid<char>>;
+    type Part1 = Option<usize>;
+    type Part2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut errors = Errors::new();
+        let patterns = input
+            .split("\n\n")
+            .filter(|block| !block.trim().is_empty())
+            .filter_map(|block| {
+                let pattern = Grid::parse_with(block, |c| match c {
+                    '#' | '.' => Ok(c),
+                    c => Err(invalid_tile(c, "`#` or `.`")),
+                });
+                errors.check(pattern.map_err(|err| err.offset(Location::of(input, block))))
+            })
+            .collect();
+
+        errors.finish(patterns)
+    }
+
+    fn part1(input: &Self::Input) -> Option<usize> {
+        input
+            .iter()
+            .map(|pattern| {
+                let (reflection, _) = get_reflection(pattern);
+                Some(reflection?.summary())
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Option<usize> {
+        input
+            .iter()
+            .map(|pattern| {
+                let (_, runner_up) = get_reflection(pattern);
+                // Only a line with a single smudge on it, which shows up as
+                // the two positions mirroring each other, counts.
+                let (reflection, smudges) = runner_up?;
+                (smudges.len() == 2).then(|| reflection.summary())
+            })
+            .sum()
+    }
+}
+
+/// Whether a pattern is mirrored across a line between two rows or between two
+/// columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectionKind {
+    Horizontal,
+    Vertical,
+}
+
+/// A line of reflection, lying just before row or column `index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reflection {
+    pub kind: ReflectionKind,
+    pub index: usize,
+}
+
+impl Reflection {
+    /// The number of columns left of a vertical line, or 100 times the number
+    /// of rows above a horizontal one.
+    pub fn summary(&self) -> usize {
+        match self.kind {
+            ReflectionKind::Horizontal => self.index * 100,
+            ReflectionKind::Vertical => self.index,
+        }
+    }
+}
+
+/// A line of reflection that does not quite fit, with the positions that would
+/// have to change for it to.
+pub type Candidate = (Reflection, HashSet<Position>);
+
+/// Finds the perfect reflection in `pattern`, if there is one, along with the
+/// imperfect one that needs the fewest changes, if any line is imperfect.
+pub fn get_reflection(pattern: &Grid<char>) -> (Option<Reflection>, Option<Candidate>) {
+    let mut reflection: Option<Reflection> = None;
+    let mut runner_up: Option<(Reflection, HashSet<Position>)> = None;
+    for (kind, len) in [
+        (ReflectionKind::Horizontal, pattern.height()),
+        (ReflectionKind::Vertical, pattern.width()),
+    ] {
+        for index in 1..len {
+            let axis = match kind {
+                ReflectionKind::Horizontal => Axis::Row(index),
+                ReflectionKind::Vertical => Axis::Column(index),
+            };
+            let differences: HashSet<Position> = pattern
+                .mirror_differences(axis)
+                .flat_map(|(a, b)| [a, b])
+                .collect();
+
+            let potential_reflection = Reflection { kind, index };
+
+            if differences.is_empty() {
+                reflection = Some(potential_reflection);
+            } else {
+                match &runner_up {
+                    None => {
+                        runner_up = Some((potential_reflection, differences));
+                    }
+                    Some((_, min_differences)) => {
+                        if differences.len() < min_differences.len() {
+                            runner_up = Some((potential_reflection, differences));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (reflection, runner_up)
+}
@@ -0,0 +1,119 @@
+use std::ops::IndexMut;
+
+use crate::{error::Error, grid::Grid, input::invalid_tile, solution::Solution};
+
+const TOTAL_CYCLES: usize = 1000000000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse_with(input, |c| match c {
+            'O' | '#' | '.' => Ok(c),
+            c => Err(invalid_tile(c, "`O`, `#` or `.`")),
+        })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let mut grid = input.clone();
+        tilt(&mut grid, Tilt::North);
+
+        total_load(&grid)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        // The load after each cycle so far, and the platform it came from.
+        // Only platforms with the same load can be the same, so only those
+        // are compared cell by cell.
+        let mut history: Vec<(usize, Grid<char>)> = Vec::new();
+        let mut grid = input.clone();
+        for cycles in 0..TOTAL_CYCLES {
+            let load = total_load(&grid);
+            let repeat = history.iter().position(|(seen_load, seen)| {
+                *seen_load == load && seen.diff_up_to(&grid, 1).is_empty()
+            });
+            if let Some(loop_start) = repeat {
+                // Every cycle from here on is one already seen, so TOTAL_CYCLES
+                // lands partway around the loop.
+                let loop_len = cycles - loop_start;
+                return history[loop_start + (TOTAL_CYCLES - loop_start) % loop_len].0;
+            }
+
+            history.push((load, grid.clone()));
+            cycle(&mut grid);
+        }
+
+        total_load(&grid)
+    }
+}
+
+/// The load on the north support beams from the round rocks.
+pub fn total_load(grid: &Grid<char>) -> usize {
+    grid.rows().enumerate().fold(0, |acc, (i, row)| {
+        acc + ((grid.height() - i) * row.iter().filter(|c| **c == 'O').count())
+    })
+}
+
+/// Tilts the platform north, west, south and then east.
+pub fn cycle(grid: &mut Grid<char>) {
+    tilt(grid, Tilt::North);
+    tilt(grid, Tilt::West);
+    tilt(grid, Tilt::South);
+    tilt(grid, Tilt::East);
+}
+
+/// The edge of the platform it is tipped towards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tilt {
+    North,
+    West,
+    South,
+    East,
+}
+
+/// Rolls every round rock as far as it will go towards `towards`.
+pub fn tilt(grid: &mut Grid<char>, towards: Tilt) {
+    match towards {
+        Tilt::North | Tilt::South => {
+            for x in 0..grid.width() {
+                let mut column = grid.column_mut(x);
+                let len = column.len();
+                roll(&mut column, len, towards == Tilt::North);
+            }
+        }
+        Tilt::West | Tilt::East => {
+            for row in grid.rows_mut() {
+                let len = row.len();
+                roll(row, len, towards == Tilt::West);
+            }
+        }
+    }
+}
+
+/// Rolls the round rocks along a line of `len` tiles towards its start, or
+/// towards its end if not `towards_start`.
+fn roll<L>(line: &mut L, len: usize, towards_start: bool)
+where
+    L: IndexMut<usize, Output = char> + ?Sized,
+{
+    let index = |i: usize| if towards_start { i } else { len - 1 - i };
+    // Where the next rock comes to rest, counting from the end it rolls to.
+    let mut free = 0;
+    for i in 0..len {
+        match line[index(i)] {
+            '#' => free = i + 1,
+            'O' => {
+                line[index(i)] = '.';
+                line[index(free)] = 'O';
+                free += 1;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    error::{Error, Errors},
+    input::parse_token,
+    solution::Solution,
+};
+
+pub struct Day15;
+
+/// A labelled lens in one of the boxes.
+#[derive(Debug, Clone)]
+pub struct Lens<'a> {
+    pub label: &'a str,
+    pub focal_length: u8,
+}
+
+/// One step of the initialization sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The step as written, which is what gets hashed.
+    pub text: String,
+    pub label: String,
+    /// The lens to put in the labelled box, or `None` to take its lens out.
+    pub focal_length: Option<u8>,
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Step>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut errors = Errors::new();
+        let steps = input
+            .split(',')
+            .filter_map(|text| {
+                let text = text.trim();
+                let focal_length = match text.find(['-', '=']) {
+                    Some(i) if text[i..] == *"-" => Ok((i, None)),
+                    Some(i) if text[i..].starts_with('=') => {
+                        parse_token(input, &text[i + 1..], "a focal length")
+                            .map(|focal_length| (i, Some(focal_length)))
+                    }
+                    _ => Err(Error::invalid_token(
+                        input,
+                        text,
+                        "`<label>-` or `<label>=<focal length>`",
+                    )),
+                };
+                let step = focal_length.map(|(i, focal_length)| Step {
+                    text: text.to_string(),
+                    label: text[..i].to_string(),
+                    focal_length,
+                });
+                errors.check(step)
+            })
+            .collect();
+
+        errors.finish(steps)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.iter().map(|step| get_hash(&step.text)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        let mut boxes: Vec<Vec<Lens>> = vec![Vec::new(); 256];
+        for step in input {
+            let label = step.label.as_str();
+            let lenses = &mut boxes[get_hash(label) as usize];
+
+            match step.focal_length {
+                None => lenses.retain(|lens| lens.label != label),
+                Some(focal_length) => match lenses.iter_mut().find(|lens| lens.label == label) {
+                    Some(lens) => lens.focal_length = focal_length,
+                    None => lenses.push(Lens {
+                        label,
+                        focal_length,
+                    }),
+                },
+            }
+        }
+
+        let mut part2 = 0;
+        for (box_index, b) in boxes.iter().enumerate() {
+            for (slot_index, lens) in b.iter().enumerate() {
+                part2 += (box_index + 1) * (slot_index + 1) * usize::from(lens.focal_length);
+            }
+        }
+
+        part2
+    }
+}
+
+/// The HASH algorithm, ignoring whitespace such as trailing newlines.
+pub fn get_hash(s: &str) -> u32 {
+    let mut current_value = 0;
+    for c in s.chars() {
+        if c.is_ascii_whitespace() {
+            continue;
+        }
+
+        current_value += c as u32;
+        current_value *= 17;
+        current_value %= 256;
+    }
+
+    current_value
+}
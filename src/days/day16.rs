@@ -0,0 +1,219 @@
+use crate::{
+    error::Error,
+    grid::{Direction, Grid, Position},
+    input::invalid_tile,
+    solution::Solution,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Grid<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        energize_grid(
+            grid,
+            Beam {
+                pos: Position(0, 0),
+                heading: Heading::East,
+            },
+        )
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        let mut energized = 0;
+        for x in 0..grid.width() {
+            energized = energize_grid(
+                grid,
+                Beam {
+                    pos: Position(x, 0),
+                    heading: Heading::South,
+                },
+            )
+            .max(energized);
+            energized = energize_grid(
+                grid,
+                Beam {
+                    pos: Position(x, grid.height() - 1),
+                    heading: Heading::North,
+                },
+            )
+            .max(energized);
+        }
+
+        for y in 0..grid.height() {
+            energized = energize_grid(
+                grid,
+                Beam {
+                    pos: Position(0, y),
+                    heading: Heading::East,
+                },
+            )
+            .max(energized);
+            energized = energize_grid(
+                grid,
+                Beam {
+                    pos: Position(grid.width() - 1, y),
+                    heading: Heading::West,
+                },
+            )
+            .max(energized);
+        }
+
+        energized
+    }
+}
+
+/// A tile of the contraption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    /// A `/` mirror.
+    ForwardMirror,
+    /// A `\` mirror.
+    BackMirror,
+    /// A `|` splitter.
+    VerticalSplitter,
+    /// A `-` splitter.
+    HorizontalSplitter,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::ForwardMirror),
+            '\\' => Ok(Tile::BackMirror),
+            '|' => Ok(Tile::VerticalSplitter),
+            '-' => Ok(Tile::HorizontalSplitter),
+            c => Err(invalid_tile(c, "`.`, `/`, `\\`, `|` or `-`")),
+        }
+    }
+}
+
+/// The way a beam travels, which is always along one of the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    pub fn direction(self) -> Direction {
+        match self {
+            Heading::North => Direction::North,
+            Heading::East => Direction::East,
+            Heading::South => Direction::South,
+            Heading::West => Direction::West,
+        }
+    }
+
+    /// A different bit for each heading.
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A beam of light on the tile at `pos`, travelling towards `heading`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Beam {
+    pub pos: Position,
+    pub heading: Heading,
+}
+
+/// How many tiles end up energized by a beam entering the contraption as
+/// `beam`.
+pub fn energize_grid(grid: &Grid<Tile>, beam: Beam) -> usize {
+    trace(grid, beam)
+        .cells()
+        .iter()
+        .filter(|headings| **headings != 0)
+        .count()
+}
+
+/// Which tiles end up energized by a beam entering the contraption as
+/// `beam`.
+pub fn energized(grid: &Grid<Tile>, beam: Beam) -> Grid<bool> {
+    let seen = trace(grid, beam);
+    Grid::from_fn(grid.width(), grid.height(), |pos| seen[pos] != 0)
+}
+
+/// Follows a beam entering the contraption as `beam` and every beam split
+/// from it, returning the headings beams passed through each tile with, one
+/// bit each.
+fn trace(grid: &Grid<Tile>, beam: Beam) -> Grid<u8> {
+    let mut seen: Grid<u8> = Grid::filled(grid.width(), grid.height(), 0);
+    let mut beams: Vec<Beam> = vec![beam];
+    let mut new_beams: Vec<Beam> = Vec::new();
+
+    while !beams.is_empty() {
+        beams.retain_mut(|beam| {
+            seen[beam.pos] |= beam.heading.bit();
+            match grid[beam.pos] {
+                Tile::Empty => {}
+                // Mirrors swap the beam's horizontal and vertical movement.
+                Tile::ForwardMirror => {
+                    beam.heading = match beam.heading {
+                        Heading::North => Heading::East,
+                        Heading::East => Heading::North,
+                        Heading::South => Heading::West,
+                        Heading::West => Heading::South,
+                    };
+                }
+                Tile::BackMirror => {
+                    beam.heading = match beam.heading {
+                        Heading::North => Heading::West,
+                        Heading::West => Heading::North,
+                        Heading::South => Heading::East,
+                        Heading::East => Heading::South,
+                    };
+                }
+                Tile::VerticalSplitter => match beam.heading {
+                    Heading::West | Heading::East => {
+                        beam.heading = Heading::North;
+                        new_beams.push(Beam {
+                            pos: beam.pos,
+                            heading: Heading::South,
+                        });
+                    }
+                    Heading::North | Heading::South => {}
+                },
+                Tile::HorizontalSplitter => match beam.heading {
+                    Heading::North | Heading::South => {
+                        beam.heading = Heading::East;
+                        new_beams.push(Beam {
+                            pos: beam.pos,
+                            heading: Heading::West,
+                        });
+                    }
+                    Heading::West | Heading::East => {}
+                },
+            }
+
+            let Some(pos) = grid.step(beam.pos, beam.heading.direction(), 1) else {
+                return false;
+            };
+            beam.pos = pos;
+
+            seen[beam.pos] & beam.heading.bit() == 0
+        });
+
+        if !new_beams.is_empty() {
+            beams.append(&mut new_beams);
+        }
+    }
+
+    seen
+}
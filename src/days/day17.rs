@@ -0,0 +1,122 @@
+use crate::{
+    error::Error,
+    grid::{Direction, Grid, Position, RelativeDirection},
+    input::invalid_tile,
+    search::{self, Search},
+    solution::Solution,
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Grid<u32>;
+    type Part1 = Option<u64>;
+    type Part2 = Option<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse_with(input, |c| match c {
+            '0'..='9' => Ok(c as u32 - '0' as u32),
+            c => Err(invalid_tile(c, "a heat loss digit")),
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Option<u64> {
+        crucible_dijkstra(
+            grid,
+            Position(0, 0),
+            Position(grid.width() - 1, grid.height() - 1),
+            CrucibleType::Basic,
+        )
+        .path
+        .map(|path| path.cost)
+    }
+
+    fn part2(grid: &Self::Input) -> Option<u64> {
+        crucible_dijkstra(
+            grid,
+            Position(0, 0),
+            Position(grid.width() - 1, grid.height() - 1),
+            CrucibleType::Ultra,
+        )
+        .path
+        .map(|path| path.cost)
+    }
+}
+
+/// A crucible at the end of a straight run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CrucibleState {
+    pub pos: Position,
+    /// The direction of the last run and how many blocks it was, or zero
+    /// blocks at the start.
+    pub last_move: (Direction, u8),
+}
+
+/// How far a crucible must and may move before turning: 1 to 3 blocks for a
+/// basic crucible, 4 to 10 for an ultra crucible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrucibleType {
+    Basic,
+    Ultra,
+}
+
+impl CrucibleType {
+    fn run_lengths(self) -> std::ops::RangeInclusive<usize> {
+        match self {
+            CrucibleType::Basic => 1..=3,
+            CrucibleType::Ultra => 4..=10,
+        }
+    }
+}
+
+/// The path from `start` to `goal` that loses the least heat, as a series of
+/// straight runs.
+pub fn crucible_dijkstra(
+    grid: &Grid<u32>,
+    start: Position,
+    goal: Position,
+    crucible_type: CrucibleType,
+) -> Search<CrucibleState, u64> {
+    let start = CrucibleState {
+        pos: start,
+        last_move: (Direction::North, 0),
+    };
+
+    search::dijkstra(
+        start,
+        |state| {
+            let (last_direction, last_blocks) = state.last_move;
+            let mut runs = Vec::new();
+            for &direction in Direction::all() {
+                // Each run turns, except the first, which can go any way.
+                if last_blocks != 0
+                    && (direction == last_direction
+                        || direction.turned(RelativeDirection::Backward) == last_direction)
+                {
+                    continue;
+                }
+
+                let mut heat_loss = 0;
+                for blocks in 1..=*crucible_type.run_lengths().end() {
+                    let Some(pos) = grid.step(state.pos, direction, blocks) else {
+                        break;
+                    };
+
+                    heat_loss += grid[pos] as u64;
+                    if crucible_type.run_lengths().contains(&blocks) {
+                        let next = CrucibleState {
+                            pos,
+                            last_move: (direction, blocks as u8),
+                        };
+                        runs.push((next, heat_loss));
+                    }
+                }
+            }
+
+            runs
+        },
+        |state| state.pos == goal,
+    )
+}
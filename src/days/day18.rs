@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use crate::{
+    error::{both, Error, Location},
+    grid::{Direction, SignedPosition, Vector},
+    input::{next_field, parse_lines, parse_token},
+    polygon::{Polygon, PolygonError},
+    solution::Solution,
+};
+
+pub struct Day18;
+
+pub struct DigPlan {
+    pub instructions: Vec<Instruction>,
+    /// The instructions hidden in each line's color code.
+    pub true_instructions: Vec<Instruction>,
+    /// The trench dug by `instructions`.
+    pub trench: Polygon,
+    /// The trench dug by `true_instructions`.
+    pub true_trench: Polygon,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = DigPlan;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let plan = parse_lines(input, |line| {
+            let mut pieces = line.split_ascii_whitespace();
+            let direction = next_field(line, &mut pieces, "direction").and_then(|direction_str| {
+                Direction::from_str(direction_str)
+                    .map_err(|err| err.offset(Location::of(line, direction_str)))
+            });
+            let dist = next_field(line, &mut pieces, "distance")
+                .and_then(|dist_str| parse_token(line, dist_str, "a distance"));
+            let true_instruction = next_field(line, &mut pieces, "color").and_then(|color| {
+                let hex = color
+                    .strip_prefix('(')
+                    .and_then(|color| color.strip_suffix(')'))
+                    .ok_or_else(|| Error::invalid_token(line, color, "`(#<hex>)`"))?;
+                Instruction::from_hex(hex).map_err(|err| err.offset(Location::of(line, hex)))
+            });
+
+            let ((direction, dist), true_instruction) =
+                both(both(direction, dist), true_instruction)?;
+            Ok((Instruction::new(direction, dist), true_instruction))
+        })?;
+
+        let lines: Vec<&str> = input.lines().filter(|line| !line.is_empty()).collect();
+        let (instructions, true_instructions): (Vec<_>, Vec<_>) = plan.into_iter().unzip();
+        let (trench, true_trench) = both(
+            dig(input, &lines, &instructions, 1),
+            dig(input, &lines, &true_instructions, 2),
+        )?;
+
+        Ok(DigPlan {
+            instructions,
+            true_instructions,
+            trench,
+            true_trench,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input.trench.total_area() as usize
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input.true_trench.total_area() as usize
+    }
+}
+
+/// The trench dug by `instructions`, each read from the same line of `lines`.
+///
+/// A trench that crosses itself is blamed on the field numbered `field` of the
+/// line whose run first crosses, counting from 0.
+fn dig(
+    input: &str,
+    lines: &[&str],
+    instructions: &[Instruction],
+    field: usize,
+) -> Result<Polygon, Error> {
+    Polygon::from_runs(
+        instructions
+            .iter()
+            .map(|instruction| (instruction.direction, instruction.dist)),
+    )
+    .map_err(|err| match err {
+        PolygonError::Unclosed { .. } => {
+            Error::missing_field(input, "instructions leading back to the start")
+        }
+        PolygonError::TooFewVertices => {
+            Error::missing_field(input, "instructions enclosing an area")
+        }
+        PolygonError::SelfIntersecting {
+            edges: [_, (start, end)],
+        } => {
+            let line = run_from(instructions, start, end)
+                .and_then(|i| lines.get(i))
+                .or(lines.last())
+                .copied()
+                .unwrap_or(input);
+            let token = line.split_ascii_whitespace().nth(field).unwrap_or(line);
+            Error::invalid_token(input, token, "a trench that doesn't cross itself")
+        }
+    })
+}
+
+/// The index of the first instruction that digs from `start` towards `end`.
+fn run_from(
+    instructions: &[Instruction],
+    start: SignedPosition,
+    end: SignedPosition,
+) -> Option<usize> {
+    let heading = end - start;
+    let heading = Vector(heading.0.signum(), heading.1.signum());
+    let mut pos = SignedPosition(0, 0);
+    instructions.iter().position(|instruction| {
+        let from = pos;
+        pos = pos + instruction.direction.unit_vector() * instruction.dist as isize;
+        from == start && instruction.direction.unit_vector() == heading
+    })
+}
+
+/// Dig `dist` meters in `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub dist: usize,
+}
+
+impl Instruction {
+    pub fn new(direction: Direction, dist: usize) -> Instruction {
+        Instruction { direction, dist }
+    }
+
+    /// Decodes a color code like `#70c710`, whose first five hex digits are
+    /// the distance and whose last is the direction.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let digits = hex
+            .strip_prefix('#')
+            .ok_or_else(|| Error::invalid_token(hex, hex, "a hex color starting with `#`"))?;
+        if digits.len() != 6 || !digits.is_ascii() {
+            return Err(Error::invalid_token(hex, digits, "six hex digits"));
+        }
+
+        let (dist_str, direction_str) = digits.split_at(5);
+        let dist = usize::from_str_radix(dist_str, 16)
+            .map_err(|_| Error::invalid_token(hex, dist_str, "a hex distance"))?;
+        let direction = match direction_str {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            _ => {
+                return Err(Error::out_of_range(
+                    hex,
+                    direction_str,
+                    "a hex direction from 0 to 3",
+                ));
+            }
+        };
+
+        Ok(Instruction { direction, dist })
+    }
+}
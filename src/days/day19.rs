@@ -0,0 +1,339 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    error::{Error, Errors, Location},
+    input::parse_token,
+    solution::Solution,
+};
+
+pub struct Day19;
+
+pub struct System {
+    /// Every workflow, by label.
+    pub workflows: HashMap<String, Workflow>,
+    pub items: Vec<Item>,
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = System;
+    type Part1 = Option<u32>;
+    type Part2 = ();
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut workflows: HashMap<String, Workflow> = HashMap::new();
+        let mut items: Vec<Item> = Vec::new();
+        let mut in_workflows = true;
+        let mut errors = Errors::new();
+
+        // Labels of every workflow line, even malformed ones, so that a bad
+        // workflow is not reported again by everything that refers to it.
+        let labels: HashSet<&str> = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .filter_map(|line| line.split_once('{').map(|(label, _)| label))
+            .collect();
+
+        for (i, line) in input.lines().enumerate() {
+            if in_workflows {
+                if line.is_empty() {
+                    in_workflows = false;
+                    continue;
+                }
+
+                if let Some(workflow) =
+                    errors.check(Workflow::from_str(line).map_err(|err| err.on_line(i + 1)))
+                {
+                    workflows.insert(workflow.label.clone(), workflow);
+                }
+
+                for dest in destinations(line) {
+                    if !matches!(dest, "A" | "R") && !labels.contains(dest) {
+                        errors.push(
+                            Error::invalid_token(line, dest, "a defined workflow, `A` or `R`")
+                                .on_line(i + 1),
+                        );
+                    }
+                }
+            } else {
+                if line.is_empty() {
+                    continue;
+                }
+
+                if let Some(item) =
+                    errors.check(Item::from_str(line).map_err(|err| err.on_line(i + 1)))
+                {
+                    items.push(item);
+                }
+            }
+        }
+
+        if !labels.contains("in") {
+            errors.push(Error::missing_field(input, "an `in` workflow"));
+        }
+
+        errors.finish(System { workflows, items })
+    }
+
+    fn part1(input: &Self::Input) -> Option<u32> {
+        input
+            .items
+            .iter()
+            .map(|item| {
+                let accepted = check_item(&input.workflows, item)?;
+                Some(if accepted { item.total() } else { 0 })
+            })
+            .sum()
+    }
+
+    fn part2(_input: &Self::Input) {}
+}
+
+/// Whether `item` is accepted after running it through `workflows`, starting
+/// at `in`, or `None` if it is sent to a workflow that doesn't exist or goes
+/// round in circles.
+pub fn check_item(workflows: &HashMap<String, Workflow>, item: &Item) -> Option<bool> {
+    let mut label = "in";
+    // A part sent on more times than there are workflows has been through one
+    // of them twice, and will keep going round.
+    for _ in 0..=workflows.len() {
+        match label {
+            "A" => return Some(true),
+            "R" => return Some(false),
+            _ => label = workflows.get(label)?.next(item),
+        }
+    }
+
+    None
+}
+
+/// Every label a workflow line sends parts to, as slices of `line`.
+fn destinations(line: &str) -> impl Iterator<Item = &str> {
+    line.split_once('{')
+        .and_then(|(_, rules)| rules.strip_suffix('}'))
+        .into_iter()
+        .flat_map(|rules| rules.split(','))
+        .map(|rule| rule.split_once(':').map_or(rule, |(_, dest)| dest))
+        .filter(|dest| !dest.is_empty())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    LessThan,
+    GreaterThan,
+}
+
+impl FromStr for Operator {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "<" => Ok(Operator::LessThan),
+            ">" => Ok(Operator::GreaterThan),
+            _ => Err(Error::invalid_token(s, s, "`<` or `>`")),
+        }
+    }
+}
+
+/// One of the four ratings each part has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    ExtremelyCoolLooking,
+    Musical,
+    Aerodynamic,
+    Shiny,
+}
+
+/// A machine part and its rating in each category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub x: u32,
+    pub m: u32,
+    pub a: u32,
+    pub s: u32,
+}
+
+impl FromStr for Item {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let ratings = str
+            .strip_prefix('{')
+            .and_then(|ratings| ratings.strip_suffix('}'))
+            .ok_or_else(|| Error::invalid_token(str, str, "`{<ratings>}`"))?;
+
+        let mut x: Option<u32> = None;
+        let mut m: Option<u32> = None;
+        let mut a: Option<u32> = None;
+        let mut s: Option<u32> = None;
+
+        for piece in ratings.split(',') {
+            let (category_str, value_str) = piece
+                .split_once('=')
+                .ok_or_else(|| Error::invalid_token(str, piece, "`<category>=<rating>`"))?;
+            let category = Category::from_str(category_str)
+                .map_err(|err| err.offset(Location::of(str, category_str)))?;
+            let value = parse_token(str, value_str, "a rating")?;
+            match category {
+                Category::ExtremelyCoolLooking => {
+                    x = Some(value);
+                }
+                Category::Musical => {
+                    m = Some(value);
+                }
+                Category::Aerodynamic => {
+                    a = Some(value);
+                }
+                Category::Shiny => {
+                    s = Some(value);
+                }
+            }
+        }
+
+        Ok(Item {
+            x: x.ok_or_else(|| Error::missing_field(str, "x rating"))?,
+            m: m.ok_or_else(|| Error::missing_field(str, "m rating"))?,
+            a: a.ok_or_else(|| Error::missing_field(str, "a rating"))?,
+            s: s.ok_or_else(|| Error::missing_field(str, "s rating"))?,
+        })
+    }
+}
+
+impl Item {
+    pub fn new(x: u32, m: u32, a: u32, s: u32) -> Item {
+        Item { x, m, a, s }
+    }
+
+    /// The sum of every rating.
+    pub fn total(&self) -> u32 {
+        self.x + self.m + self.a + self.s
+    }
+
+    pub fn get_category(&self, category: Category) -> u32 {
+        match category {
+            Category::ExtremelyCoolLooking => self.x,
+            Category::Musical => self.m,
+            Category::Aerodynamic => self.a,
+            Category::Shiny => self.s,
+        }
+    }
+}
+
+impl FromStr for Category {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x" => Ok(Category::ExtremelyCoolLooking),
+            "m" => Ok(Category::Musical),
+            "a" => Ok(Category::Aerodynamic),
+            "s" => Ok(Category::Shiny),
+            _ => Err(Error::invalid_token(s, s, "`x`, `m`, `a` or `s`")),
+        }
+    }
+}
+
+/// Sends parts whose `subject` rating compares to `operand` by `operator` on to
+/// the workflow labelled `dest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub subject: Category,
+    pub operator: Operator,
+    pub operand: u32,
+    pub dest: String,
+}
+
+impl Rule {
+    pub fn matches(&self, item: &Item) -> bool {
+        let subject = item.get_category(self.subject);
+        match self.operator {
+            Operator::LessThan => subject < self.operand,
+            Operator::GreaterThan => subject > self.operand,
+        }
+    }
+}
+
+/// A named list of rules, tried in order, sending parts that match none of
+/// them to `fallback`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflow {
+    pub label: String,
+    pub rules: Vec<Rule>,
+    pub fallback: String,
+}
+
+impl Workflow {
+    /// The label of the workflow `item` is sent to next, or `A` or `R` if it
+    /// is accepted or rejected.
+    pub fn next(&self, item: &Item) -> &str {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(item))
+            .map_or(&self.fallback, |rule| &rule.dest)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (comparison, dest) = s
+            .split_once(':')
+            .ok_or_else(|| Error::missing_field(s, "`:<destination>`"))?;
+        let subject_str = comparison
+            .get(..1)
+            .ok_or_else(|| Error::missing_field(comparison, "category"))?;
+        let operator_str = comparison
+            .get(1..2)
+            .ok_or_else(|| Error::missing_field(comparison, "operator"))?;
+        let subject = Category::from_str(subject_str)?;
+        let operator =
+            Operator::from_str(operator_str).map_err(|err| err.offset(Location::new(1, 2)))?;
+        let operand = parse_token(s, &comparison[2..], "a rating")?;
+
+        Ok(Rule {
+            subject,
+            operator,
+            operand,
+            dest: dest.to_string(),
+        })
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, rules_str) = s
+            .split_once('{')
+            .ok_or_else(|| Error::missing_field(s, "`{<rules>}`"))?;
+        let rules_str = rules_str
+            .strip_suffix('}')
+            .ok_or_else(|| Error::missing_field(s, "closing `}`"))?;
+
+        let mut errors = Errors::new();
+        let mut rule_strs: Vec<&str> = rules_str.split(',').collect();
+        let fallback = rule_strs.pop().unwrap_or_default();
+        if fallback.is_empty() {
+            errors.push(Error::missing_field(s, "fallback workflow"));
+        }
+
+        let rules = rule_strs
+            .into_iter()
+            .filter_map(|rule_str| {
+                errors.check(
+                    Rule::from_str(rule_str).map_err(|err| err.offset(Location::of(s, rule_str))),
+                )
+            })
+            .collect();
+
+        errors.finish(Workflow {
+            label: label.to_string(),
+            rules,
+            fallback: fallback.to_string(),
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use crate::{error::Error, input::parse_lines, solution::Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = HashMap<String, Module>;
+    type Part1 = u64;
+    type Part2 = Option<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
+        let mut modules: HashMap<String, Module> = parse_lines(input, |line| {
+            let module = Module::from_str(line)?;
+            for output in &module.outputs {
+                inputs
+                    .entry(output.to_string())
+                    .or_default()
+                    .push(module.label.clone());
+            }
+
+            Ok((module.label.clone(), module))
+        })?
+        .into_iter()
+        .collect();
+
+        for module in modules.values_mut() {
+            if let ModuleType::Conjunction { input_memory } = &mut module.module_type {
+                *input_memory = inputs
+                    .remove(&module.label)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|i| (i, Pulse::Low))
+                    .collect();
+            }
+        }
+
+        Ok(modules)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        let mut modules = input.clone();
+        let mut all_pulses: HashMap<Pulse, u64> = HashMap::new();
+
+        for _ in 0..1000 {
+            push_button(&mut modules, |_, pulse| {
+                *all_pulses.entry(pulse).or_default() += 1;
+            });
+        }
+
+        all_pulses.get(&Pulse::Low).copied().unwrap_or_default()
+            * all_pulses.get(&Pulse::High).copied().unwrap_or_default()
+    }
+
+    fn part2(input: &Self::Input) -> Option<u64> {
+        // Without a module feeding `rx`, no pulse ever reaches it.
+        if !input
+            .values()
+            .any(|module| module.outputs.iter().any(|o| o == "rx"))
+        {
+            return None;
+        }
+
+        let mut modules = input.clone();
+
+        let mut i = 0;
+        loop {
+            i += 1;
+            let mut done = false;
+            push_button(&mut modules, |target, pulse| {
+                if target == "rx" && pulse == Pulse::Low {
+                    done = true;
+                }
+            });
+
+            if done {
+                return Some(i);
+            }
+        }
+    }
+}
+
+/// Sends a single low pulse to the broadcaster and runs until all pulses
+/// have been delivered, calling `on_pulse` with each pulse's target.
+pub fn push_button(modules: &mut HashMap<String, Module>, mut on_pulse: impl FnMut(&str, Pulse)) {
+    let mut pulses: VecDeque<(String, String, Pulse)> = VecDeque::new();
+    pulses.push_back(("broadcaster".to_string(), "".to_string(), Pulse::Low));
+
+    while let Some((target, from, pulse)) = pulses.pop_front() {
+        on_pulse(&target, pulse);
+
+        if let Some(target_module) = modules.get_mut(&target) {
+            if let Some(output_pulse) = target_module.receive_pulse(from, pulse) {
+                for dest in &target_module.outputs {
+                    pulses.push_back((dest.clone(), target_module.label.clone(), output_pulse));
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+/// A communication module, which passes pulses on to each of its `outputs`.
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub label: String,
+    pub outputs: Vec<String>,
+    pub module_type: ModuleType,
+}
+
+impl Module {
+    pub fn new(label: &str, outputs: Vec<String>, module_type: ModuleType) -> Module {
+        Module {
+            label: label.to_string(),
+            outputs,
+            module_type,
+        }
+    }
+
+    /// Handles `pulse` from the module labelled `input`, returning the pulse
+    /// to send to every output, if any.
+    pub fn receive_pulse(&mut self, input: String, pulse: Pulse) -> Option<Pulse> {
+        match &mut self.module_type {
+            ModuleType::FlipFlop { on } => match pulse {
+                Pulse::High => None,
+                Pulse::Low => {
+                    *on = !*on;
+                    if *on {
+                        Some(Pulse::High)
+                    } else {
+                        Some(Pulse::Low)
+                    }
+                }
+            },
+            ModuleType::Conjunction { input_memory } => {
+                let entry = input_memory.entry(input).or_insert(Pulse::Low);
+                *entry = pulse;
+                if input_memory.values().all(|p| *p == Pulse::High) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+            ModuleType::Broadcast => Some(pulse),
+        }
+    }
+}
+
+/// What a module does with the pulses it receives, and the state it keeps to
+/// do so.
+#[derive(Debug, Clone)]
+pub enum ModuleType {
+    /// Ignores high pulses and toggles on low ones, sending high when turning
+    /// on and low when turning off.
+    FlipFlop { on: bool },
+    /// Remembers the last pulse from each input, sending low once all of them
+    /// are high and high otherwise.
+    Conjunction {
+        input_memory: HashMap<String, Pulse>,
+    },
+    /// Repeats every pulse it receives.
+    Broadcast,
+}
+
+impl FromStr for Module {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, outputs) = s
+            .split_once(" -> ")
+            .ok_or_else(|| Error::missing_field(s, "` -> <outputs>`"))?;
+        let outputs: Vec<String> = outputs.split(", ").map(String::from).collect();
+        if label == "broadcaster" {
+            Ok(Module::new(label, outputs, ModuleType::Broadcast))
+        } else if let Some(label) = label.strip_prefix('%') {
+            Ok(Module::new(
+                label,
+                outputs,
+                ModuleType::FlipFlop { on: false },
+            ))
+        } else if let Some(label) = label.strip_prefix('&') {
+            Ok(Module::new(
+                label,
+                outputs,
+                ModuleType::Conjunction {
+                    input_memory: HashMap::new(),
+                },
+            ))
+        } else {
+            Err(Error::invalid_token(
+                s,
+                label,
+                "`broadcaster`, `%<label>` or `&<label>`",
+            ))
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::Error,
+    grid::{Grid, Position, Tiling},
+    input::invalid_tile,
+    solution::Solution,
+};
+
+pub struct Day21;
+
+pub struct Garden {
+    pub grid: Grid<Tile>,
+    /// Where the elf starts, marked `S`.
+    pub start: Position,
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let (grid, [starts]) =
+            Grid::parse_with_markers(input, [('S', Tile::GardenPlot)], Tile::try_from)?;
+
+        Ok(Garden {
+            grid,
+            start: *starts
+                .first()
+                .ok_or_else(|| Error::missing_field(input, "start position `S`"))?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        reachable_plots(input, 64)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        infinite_reachable_plots(input, 26_501_365)
+    }
+}
+
+/// Counts the garden plots the elf could be on after exactly `steps` steps.
+pub fn reachable_plots(garden: &Garden, steps: u32) -> usize {
+    let mut states: HashSet<(Position, u32)> = HashSet::new();
+    step(&garden.grid, garden.start, steps, &mut states);
+
+    states.iter().filter(|state| state.1 == 0).count()
+}
+
+/// Like [`reachable_plots`], but with the garden repeating infinitely in
+/// every direction.
+///
+/// The plots first reached on step `d` are reachable on every later step of
+/// the same parity, so this walks outward one step at a time counting them.
+/// Once the walk has crossed enough copies of the garden, the count every
+/// `period` steps grows quadratically, so the rest is extrapolated.
+pub fn infinite_reachable_plots(garden: &Garden, steps: u32) -> usize {
+    let tiles = garden.grid.tiled(Tiling::Repeat);
+    let (width, height) = (garden.grid.width(), garden.grid.height());
+    let period = width / gcd(width, height) * height;
+    let steps = steps as usize;
+
+    let mut seen = HashSet::from([garden.start.to_signed()]);
+    let mut frontier = vec![garden.start.to_signed()];
+    // How many plots are reachable in exactly `d` steps, for each `d` so far.
+    let mut reachable = vec![1];
+    // The counts for `steps % period` steps, then a period more each time.
+    let mut samples: Vec<i128> = Vec::new();
+
+    for d in 0.. {
+        if d == steps {
+            return reachable[d];
+        }
+        if d % period == steps % period {
+            samples.push(reachable[d] as i128);
+            if let Some(count) = extrapolate(&samples, (steps - d) / period) {
+                return count;
+            }
+        }
+
+        frontier = frontier
+            .into_iter()
+            .flat_map(|pos| tiles.neighbors4(pos))
+            .filter(|(_, _, tile)| matches!(tile, Tile::GardenPlot))
+            .filter_map(|(_, next, _)| seen.insert(next).then_some(next))
+            .collect();
+        if frontier.is_empty() {
+            // The start is walled in, so nothing more is ever reachable.
+            return if (steps - d).is_multiple_of(2) {
+                reachable[d]
+            } else {
+                d.checked_sub(1).map_or(0, |d| reachable[d])
+            };
+        }
+        reachable.push(frontier.len() + if d == 0 { 0 } else { reachable[d - 1] });
+    }
+
+    unreachable!()
+}
+
+/// The value `periods` samples past the last, once the last few samples
+/// have settled into a quadratic.
+fn extrapolate(samples: &[i128], periods: usize) -> Option<usize> {
+    let [.., a, b, c, d] = *samples else {
+        return None;
+    };
+    let second = d - 2 * c + b;
+    if second != c - 2 * b + a {
+        return None;
+    }
+
+    let m = periods as i128;
+    Some((d + m * (d - c) + second * m * (m + 1) / 2) as usize)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tile {
+    GardenPlot,
+    Rock,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::GardenPlot),
+            '#' => Ok(Tile::Rock),
+            c => Err(invalid_tile(c, "`.`, `#` or `S`")),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::GardenPlot => write!(f, "."),
+            Tile::Rock => write!(f, "#"),
+        }
+    }
+}
+
+fn step(
+    grid: &Grid<Tile>,
+    pos: Position,
+    steps_remaining: u32,
+    states: &mut HashSet<(Position, u32)>,
+) {
+    if states.contains(&(pos, steps_remaining)) {
+        return;
+    }
+
+    states.insert((pos, steps_remaining));
+
+    if steps_remaining == 0 {
+        return;
+    }
+
+    for (_, new_pos, _) in grid.neighbors4_where(pos, |tile| matches!(tile, Tile::GardenPlot)) {
+        step(grid, new_pos, steps_remaining - 1, states);
+    }
+}
@@ -0,0 +1,148 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+    error::{Error, Location},
+    input::parse_lines,
+    solution::Solution,
+    Point3D,
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    /// The bricks after they have settled, sorted by height.
+    type Input = Vec<Cube>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let mut cubes: Vec<Cube> = parse_lines(input, Cube::from_str)?;
+
+        if !cubes.is_empty() {
+            cubes.sort_by_key(|cube| cube.0.z);
+            fall(&mut cubes);
+        }
+
+        Ok(cubes)
+    }
+
+    fn part1(cubes: &Self::Input) -> usize {
+        (0..cubes.len())
+            .filter(|i| is_safe_to_disintegrate(cubes, *i))
+            .count()
+    }
+
+    fn part2(cubes: &Self::Input) -> usize {
+        (0..cubes.len())
+            .filter(|i| !is_safe_to_disintegrate(cubes, *i))
+            .map(|i| {
+                let mut disintigrated = HashSet::new();
+                disintigrated.insert(i);
+                chain_reactors(cubes, i, &mut disintigrated);
+                disintigrated.len() - 1
+            })
+            .sum()
+    }
+}
+
+/// Whether every brick resting on brick `i` is also held up by another one,
+/// given settled `cubes` sorted by height.
+pub fn is_safe_to_disintegrate(cubes: &[Cube], i: usize) -> bool {
+    i == cubes.len() - 1
+        || ((i + 1)..cubes.len()).all(|j| {
+            !cubes[i].is_supporting(&cubes[j])
+                || (0..j).any(|k| k != i && cubes[k].is_supporting(&cubes[j]))
+        })
+}
+
+fn chain_reactors(cubes: &[Cube], i: usize, disintigrated: &mut HashSet<usize>) {
+    for j in (i + 1)..cubes.len() {
+        if !disintigrated.contains(&j)
+            && cubes[i].is_supporting(&cubes[j])
+            && !(0..j).any(|k| !disintigrated.contains(&k) && cubes[k].is_supporting(&cubes[j]))
+        {
+            disintigrated.insert(j);
+            if j < cubes.len() - 1 {
+                chain_reactors(cubes, j, disintigrated);
+            }
+        }
+    }
+}
+
+/// Lets bricks sorted by height drop until each rests on the ground or on
+/// another brick.
+pub fn fall(cubes: &mut [Cube]) {
+    cubes[0].1.z -= cubes[0].0.z;
+    cubes[0].0.z = 0;
+
+    for i in 1..cubes.len() {
+        while !cubes[i].is_being_supported(&cubes[0..i]) && cubes[i].0.z > 0 && cubes[i].1.z > 0 {
+            cubes[i].0.z -= 1;
+            cubes[i].1.z -= 1;
+        }
+    }
+}
+
+/// A brick of sand spanning two opposite corners, the first never above the
+/// second.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cube(pub Point3D<i32>, pub Point3D<i32>);
+
+impl Cube {
+    pub fn new(first: Point3D<i32>, second: Point3D<i32>) -> Cube {
+        Cube(first, second)
+    }
+
+    /// Whether `other` rests directly on top of this brick.
+    pub fn is_supporting(&self, other: &Cube) -> bool {
+        (self.1.z + 1 == other.0.z) && {
+            let min_self_x = self.0.x.min(self.1.x);
+            let max_self_x = self.0.x.max(self.1.x);
+
+            let min_self_y = self.0.y.min(self.1.y);
+            let max_self_y = self.0.y.max(self.1.y);
+
+            let min_other_x = other.0.x.min(other.1.x);
+            let max_other_x = other.0.x.max(other.1.x);
+
+            let min_other_y = other.0.y.min(other.1.y);
+            let max_other_y = other.0.y.max(other.1.y);
+
+            min_self_x <= max_other_x
+                && max_self_x >= min_other_x
+                && min_self_y <= max_other_y
+                && max_self_y >= min_other_y
+        }
+    }
+
+    /// Whether this brick rests directly on any of `others`.
+    pub fn is_being_supported(&self, others: &[Cube]) -> bool {
+        others.iter().any(|other| other.is_supporting(self))
+    }
+}
+
+impl FromStr for Cube {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = s
+            .split_once('~')
+            .ok_or_else(|| Error::missing_field(s, "`~<second corner>`"))?;
+        let parse_corner = |corner: &str| {
+            Point3D::from_str(corner).map_err(|err| err.offset(Location::of(s, corner)))
+        };
+        let cube = Cube::new(parse_corner(first)?, parse_corner(second)?);
+
+        if cube.0.z > cube.1.z {
+            return Err(Error::out_of_range(
+                s,
+                second,
+                "a second corner no lower than the first",
+            ));
+        }
+
+        Ok(cube)
+    }
+}
@@ -0,0 +1,177 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    error::Error,
+    grid::{Color, Direction, Grid, Layer, Position, Renderer},
+    input::invalid_tile,
+    solution::Solution,
+};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Grid<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        longest_hike(grid, true)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        longest_hike(grid, false)
+    }
+}
+
+/// The length of the longest hike from the top-left opening to the
+/// bottom-right one that never steps on the same tile twice, only going
+/// downhill on slopes if `is_slippery`.
+pub fn longest_hike(grid: &Grid<Tile>, is_slippery: bool) -> usize {
+    hike(grid, is_slippery).map_or(0, |trodden| trodden.len() - 1)
+}
+
+/// Every tile trodden on the longest hike, if there is one.
+pub fn hike(grid: &Grid<Tile>, is_slippery: bool) -> Option<HashSet<Position>> {
+    let mut trodden: HashSet<Position> = HashSet::new();
+    walk(grid, Position(1, 0), &mut trodden, is_slippery).then_some(trodden)
+}
+
+/// The trail with the longest hike drawn over it.
+pub fn draw_hike(grid: &Grid<Tile>, is_slippery: bool) -> Renderer<'_, Tile> {
+    let trodden = hike(grid, is_slippery).unwrap_or_default();
+    grid.renderer()
+        .layer(Layer::new("trodden", 'O', Color::Cyan, trodden))
+}
+
+/// Extends `trodden` with the longest walk from `pos` that ends on the bottom
+/// row, returning whether there is any such walk.
+fn walk(
+    grid: &Grid<Tile>,
+    mut pos: Position,
+    trodden: &mut HashSet<Position>,
+    is_slippery: bool,
+) -> bool {
+    trodden.insert(pos);
+    let mut options = possible_steps(grid, pos, trodden, is_slippery);
+
+    while options.len() == 1 {
+        pos = options[0];
+        trodden.insert(pos);
+        options = possible_steps(grid, pos, trodden, is_slippery);
+    }
+
+    if options.is_empty() {
+        return pos.1 == grid.height() - 1;
+    }
+
+    let mut best_trodden: Option<HashSet<Position>> = None;
+    for option in options {
+        let mut new_trodden = trodden.clone();
+        if !walk(grid, option, &mut new_trodden, is_slippery) {
+            continue;
+        }
+
+        match &mut best_trodden {
+            None => {
+                best_trodden = Some(new_trodden);
+            }
+            Some(best) => {
+                if new_trodden.len() > best.len() {
+                    *best = new_trodden;
+                }
+            }
+        }
+    }
+
+    match best_trodden {
+        Some(best) => {
+            *trodden = best;
+            true
+        }
+        None => false,
+    }
+}
+
+fn possible_steps(
+    grid: &Grid<Tile>,
+    pos: Position,
+    trodden: &HashSet<Position>,
+    is_slippery: bool,
+) -> Vec<Position> {
+    if is_slippery {
+        if let Some(Tile::Slope(slope)) = grid.get_pos(pos) {
+            return grid
+                .step(pos, slope.direction(), 1)
+                .filter(|new_pos| !trodden.contains(new_pos))
+                .into_iter()
+                .collect();
+        }
+    }
+
+    grid.neighbors4_where(pos, |tile| *tile != Tile::Forest)
+        .map(|(_, new_pos, _)| new_pos)
+        .filter(|new_pos| !trodden.contains(new_pos))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Slope),
+}
+
+/// The way a slope runs, which is always along one of the axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slope {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Slope {
+    pub fn direction(self) -> Direction {
+        match self {
+            Slope::North => Direction::North,
+            Slope::East => Direction::East,
+            Slope::South => Direction::South,
+            Slope::West => Direction::West,
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Path),
+            '#' => Ok(Tile::Forest),
+            '^' => Ok(Tile::Slope(Slope::North)),
+            '>' => Ok(Tile::Slope(Slope::East)),
+            'v' => Ok(Tile::Slope(Slope::South)),
+            '<' => Ok(Tile::Slope(Slope::West)),
+            c => Err(invalid_tile(c, "`.`, `#`, `^`, `>`, `v` or `<`")),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Path => write!(f, "."),
+            Tile::Forest => write!(f, "#"),
+            Tile::Slope(Slope::North) => write!(f, "^"),
+            Tile::Slope(Slope::East) => write!(f, ">"),
+            Tile::Slope(Slope::South) => write!(f, "v"),
+            Tile::Slope(Slope::West) => write!(f, "<"),
+        }
+    }
+}
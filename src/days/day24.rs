@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use crate::{
+    error::{Error, Location},
+    input::parse_lines,
+    solution::Solution,
+    Point, Point3D, Vector3D,
+};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = ();
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        parse_lines(input, Hailstone::from_str)
+    }
+
+    fn part1(hailstones: &Self::Input) -> usize {
+        count_intersections(hailstones, 200000000000000.0, 400000000000000.0)
+    }
+
+    fn part2(_hailstones: &Self::Input) {}
+}
+
+/// Counts the pairs of hailstone paths that cross within the test area
+/// spanning `bb_min..bb_max` on both the X and Y axes, ignoring Z.
+pub fn count_intersections(hailstones: &[Hailstone], bb_min: f64, bb_max: f64) -> usize {
+    let mut count = 0;
+    for i in 0..hailstones.len().saturating_sub(1) {
+        let h1 = &hailstones[i];
+        for h2 in &hailstones[(i + 1)..] {
+            if h1.vel.y * h2.vel.x == h2.vel.y * h1.vel.x {
+                continue;
+            }
+
+            let t1 = (h2.vel.y * (h1.pos.x - h2.pos.x) - h2.vel.x * (h1.pos.y - h2.pos.y))
+                / (h1.vel.y * h2.vel.x - h1.vel.x * h2.vel.y);
+            let t2 = (h1.vel.y * (h2.pos.x - h1.pos.x) - h1.vel.x * (h2.pos.y - h1.pos.y))
+                / (h2.vel.y * h1.vel.x - h2.vel.x * h1.vel.y);
+
+            let intersection = Point(h1.pos.x + t1 * h1.vel.x, h1.pos.y + t1 * h1.vel.y);
+
+            if t1 > 0.0
+                && bb_min < intersection.0
+                && intersection.0 < bb_max
+                && t2 > 0.0
+                && bb_min < intersection.1
+                && intersection.1 < bb_max
+            {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// A hailstone's position and its velocity per nanosecond.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hailstone {
+    pub pos: Point3D<f64>,
+    pub vel: Vector3D<f64>,
+}
+
+impl Hailstone {
+    pub fn new(pos: Point3D<f64>, vel: Vector3D<f64>) -> Hailstone {
+        Hailstone { pos, vel }
+    }
+}
+
+impl FromStr for Hailstone {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pos_str, vel_str) = s
+            .split_once(" @ ")
+            .ok_or_else(|| Error::missing_field(s, "` @ <velocity>`"))?;
+        let pos = Point3D::from_str(pos_str).map_err(|err| err.offset(Location::of(s, pos_str)))?;
+        let vel =
+            Vector3D::from_str(vel_str).map_err(|err| err.offset(Location::of(s, vel_str)))?;
+        Ok(Hailstone::new(pos, vel))
+    }
+}
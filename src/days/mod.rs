@@ -0,0 +1,60 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+
+use crate::solution::AnySolution;
+
+/// The number of days with a solution.
+pub const COUNT: u8 = 24;
+
+/// Looks up the solution for a day of the calendar.
+pub fn get(day: u8) -> Option<&'static dyn AnySolution> {
+    match day {
+        1 => Some(&day01::Day01),
+        2 => Some(&day02::Day02),
+        3 => Some(&day03::Day03),
+        4 => Some(&day04::Day04),
+        5 => Some(&day05::Day05),
+        6 => Some(&day06::Day06),
+        7 => Some(&day07::Day07),
+        8 => Some(&day08::Day08),
+        9 => Some(&day09::Day09),
+        10 => Some(&day10::Day10),
+        11 => Some(&day11::Day11),
+        12 => Some(&day12::Day12),
+        13 => Some(&day13::Day13),
+        14 => Some(&day14::Day14),
+        15 => Some(&day15::Day15),
+        16 => Some(&day16::Day16),
+        17 => Some(&day17::Day17),
+        18 => Some(&day18::Day18),
+        19 => Some(&day19::Day19),
+        20 => Some(&day20::Day20),
+        21 => Some(&day21::Day21),
+        22 => Some(&day22::Day22),
+        23 => Some(&day23::Day23),
+        24 => Some(&day24::Day24),
+        _ => None,
+    }
+}
@@ -0,0 +1,268 @@
+use std::{convert::Infallible, fmt, io};
+
+/// A 1-based line and column in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Everything that can go wrong while reading or parsing puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    /// A token that does not have the expected form.
+    InvalidToken {
+        token: String,
+        expected: &'static str,
+        location: Location,
+    },
+    /// A required piece of a line is absent.
+    MissingField {
+        field: &'static str,
+        location: Location,
+    },
+    /// A well-formed value outside of the range the puzzle allows.
+    OutOfRange {
+        value: String,
+        expected: &'static str,
+        location: Location,
+    },
+    Io(io::Error),
+    /// Several problems found in the same input, in the order they appear.
+    Multiple(Vec<Error>),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Location {
+        Location { line, column }
+    }
+
+    /// The location of `fragment` within `text`.
+    ///
+    /// `fragment` must be a subslice of `text` for the location to be
+    /// accurate, otherwise the start of `text` is used.
+    pub fn of(text: &str, fragment: &str) -> Location {
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+        if offset <= text.len() && text.is_char_boundary(offset) {
+            Location::end_of(&text[..offset])
+        } else {
+            Location::new(1, 1)
+        }
+    }
+
+    /// The location just past the end of `text`, where a missing field would be.
+    pub fn end_of(text: &str) -> Location {
+        let line_start = text.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        Location {
+            line: text.matches('\n').count() + 1,
+            column: text[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Treats `self` as relative to a fragment starting at `origin`.
+    pub fn offset(self, origin: Location) -> Location {
+        if self.line <= 1 {
+            Location {
+                line: origin.line,
+                column: origin.column + self.column - 1,
+            }
+        } else {
+            Location {
+                line: origin.line + self.line - 1,
+                column: self.column,
+            }
+        }
+    }
+}
+
+impl Error {
+    pub fn invalid_token(text: &str, token: &str, expected: &'static str) -> Error {
+        Error::InvalidToken {
+            token: token.to_string(),
+            expected,
+            location: Location::of(text, token),
+        }
+    }
+
+    pub fn missing_field(text: &str, field: &'static str) -> Error {
+        Error::MissingField {
+            field,
+            location: Location::end_of(text),
+        }
+    }
+
+    pub fn out_of_range(text: &str, value: &str, expected: &'static str) -> Error {
+        Error::OutOfRange {
+            value: value.to_string(),
+            expected,
+            location: Location::of(text, value),
+        }
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Error::InvalidToken { location, .. }
+            | Error::MissingField { location, .. }
+            | Error::OutOfRange { location, .. } => Some(*location),
+            Error::Io(_) => None,
+            Error::Multiple(errors) => errors.first().and_then(Error::location),
+        }
+    }
+
+    /// Every individual problem this error stands for.
+    pub fn as_slice(&self) -> &[Error] {
+        match self {
+            Error::Multiple(errors) => errors,
+            err => std::slice::from_ref(err),
+        }
+    }
+
+    /// Every individual problem this error stands for.
+    pub fn into_vec(self) -> Vec<Error> {
+        match self {
+            Error::Multiple(errors) => errors,
+            err => vec![err],
+        }
+    }
+
+    /// Repositions an error produced while parsing a fragment that starts at
+    /// `origin`, so that it points into the whole input.
+    pub fn offset(mut self, origin: Location) -> Error {
+        match &mut self {
+            Error::InvalidToken { location, .. }
+            | Error::MissingField { location, .. }
+            | Error::OutOfRange { location, .. } => {
+                *location = location.offset(origin);
+            }
+            Error::Io(_) => {}
+            Error::Multiple(errors) => {
+                for err in errors {
+                    *err = std::mem::replace(err, Error::Multiple(Vec::new())).offset(origin);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Moves an error produced while parsing a single line onto line `line`.
+    pub fn on_line(self, line: usize) -> Error {
+        self.offset(Location::new(line, 1))
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidToken {
+                token,
+                expected,
+                location,
+            } => write!(
+                f,
+                "{location}: invalid token `{token}`, expected {expected}"
+            ),
+            Error::MissingField { field, location } => write!(f, "{location}: missing {field}"),
+            Error::OutOfRange {
+                value,
+                expected,
+                location,
+            } => write!(
+                f,
+                "{location}: value {value} out of range, expected {expected}"
+            ),
+            Error::Io(err) => write!(f, "i/o error: {err}"),
+            Error::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{err}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Collects problems found while parsing, so that all of them can be reported
+/// together rather than stopping at the first.
+#[derive(Debug, Default)]
+pub struct Errors(Vec<Error>);
+
+impl Errors {
+    pub fn new() -> Errors {
+        Errors::default()
+    }
+
+    pub fn push(&mut self, err: Error) {
+        self.0.extend(err.into_vec());
+    }
+
+    /// The value of `result`, recording its error if there is one.
+    pub fn check<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.push(err);
+                None
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// `value` if nothing went wrong, otherwise every recorded problem.
+    pub fn finish<T>(mut self, value: T) -> Result<T> {
+        match self.0.len() {
+            0 => Ok(value),
+            1 => Err(self.0.remove(0)),
+            _ => Err(Error::Multiple(self.0)),
+        }
+    }
+}
+
+/// Both values, or the errors of whichever of them failed.
+pub fn both<A, B>(a: Result<A>, b: Result<B>) -> Result<(A, B)> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (Err(err), Ok(_)) | (Ok(_), Err(err)) => Err(err),
+        (Err(a), Err(b)) => {
+            let mut errors = a.into_vec();
+            errors.extend(b.into_vec());
+            Err(Error::Multiple(errors))
+        }
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
@@ -0,0 +1,1046 @@
+//! Synthetic puzzle inputs, for running the solvers on inputs much larger than
+//! the official ones.
+//!
+//! Every generator is deterministic for a given seed and produces input that
+//! the matching day parses. What `size` scales differs per day and is listed
+//! on each generator; [`default_size`] gives sizes close to the official
+//! inputs.
+
+use std::{fmt::Write, ops::RangeInclusive};
+
+use crate::days;
+
+/// A small deterministic PRNG (SplitMix64), so generated inputs can be
+/// reproduced from their seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        range.start() + self.below((range.end() - range.start()) as u64 + 1) as usize
+    }
+
+    pub fn signed(&mut self, range: RangeInclusive<i64>) -> i64 {
+        range.start() + self.below(range.end().abs_diff(*range.start()) + 1) as i64
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+/// A size for `day` comparable to the official puzzle input.
+pub fn default_size(day: u8) -> Option<usize> {
+    let size = match day {
+        1 => 1000,
+        2 => 100,
+        3 => 140,
+        4 => 200,
+        5 => 30,
+        6 => 4,
+        7 => 1000,
+        8 => 20,
+        9 => 200,
+        10 => 140,
+        11 => 140,
+        12 => 1000,
+        13 => 100,
+        14 => 100,
+        15 => 4000,
+        16 => 110,
+        17 => 141,
+        18 => 20,
+        19 => 500,
+        20 => 12,
+        21 => 131,
+        22 => 1200,
+        23 => 6,
+        24 => 300,
+        _ => return None,
+    };
+
+    Some(size)
+}
+
+/// Generates an input for `day`, or `None` if there is no such day.
+pub fn generate(day: u8, size: usize, rng: &mut Rng) -> Option<String> {
+    if !(1..=days::COUNT).contains(&day) {
+        return None;
+    }
+
+    let size = size.max(1);
+    let input = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 => day16(rng, size),
+        17 => day17(rng, size),
+        18 => day18(rng, size),
+        19 => day19(rng, size),
+        20 => day20(rng, size),
+        21 => day21(rng, size),
+        22 => day22(rng, size),
+        23 => day23(rng, size),
+        _ => day24(rng, size),
+    };
+
+    Some(input)
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of calibration text, each with at least one digit.
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(3..=40) {
+            if rng.chance(0.1) {
+                let word = *rng.choose(&DIGIT_WORDS);
+                line.push_str(word);
+            } else if rng.chance(0.1) {
+                line.push(char::from(b'1' + rng.below(9) as u8));
+            } else {
+                line.push(char::from(b'a' + rng.below(26) as u8));
+            }
+        }
+
+        let digit = char::from(b'1' + rng.below(9) as u8);
+        line.insert(rng.range(0..=line.len()), digit);
+        writeln!(input, "{line}").unwrap();
+    }
+
+    input
+}
+
+/// `size` games.
+fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                colors[..rng.range(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        writeln!(input, "Game {id}: {}", rounds.join("; ")).unwrap();
+    }
+
+    input
+}
+
+/// A `size` by `size` engine schematic.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < size {
+            let room = size - line.len();
+            if rng.chance(0.12) {
+                let digits = rng.range(1..=3.min(room));
+                line.push(char::from(b'1' + rng.below(9) as u8));
+                for _ in 1..digits {
+                    line.push(char::from(b'0' + rng.below(10) as u8));
+                }
+                if line.len() < size {
+                    line.push('.');
+                }
+            } else if rng.chance(0.05) {
+                line.push(*rng.choose(&['*', '#', '+', '$', '/', '=', '%', '@', '&', '-']));
+            } else {
+                line.push('.');
+            }
+        }
+        writeln!(input, "{line}").unwrap();
+    }
+
+    input
+}
+
+/// `size` scratchcards.
+///
+/// Most cards have no matches, so the number of copies in part 2 stays
+/// proportional to `size` rather than growing exponentially.
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut numbers: Vec<u32> = (1..=99).collect();
+    for id in 1..=size {
+        rng.shuffle(&mut numbers);
+        let (winning, rest) = numbers.split_at(10);
+        let matches = if rng.chance(0.7) { 0 } else { rng.range(1..=3) };
+        let mut yours: Vec<u32> = winning[..matches]
+            .iter()
+            .chain(&rest[..25 - matches])
+            .copied()
+            .collect();
+        rng.shuffle(&mut yours);
+
+        let format = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(
+            input,
+            "Card {id:>4}: {} | {}",
+            format(winning),
+            format(&yours)
+        )
+        .unwrap();
+    }
+
+    input
+}
+
+/// An almanac with `size` entries in each map.
+///
+/// Each map's source ranges are disjoint, as in the official input.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    const SPAN: u64 = 4_000_000_000;
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    let seeds: Vec<String> = (0..5)
+        .map(|_| {
+            let start = rng.below(SPAN);
+            let len = 1 + rng.below((SPAN - start).min(SPAN / 10));
+            format!("{start} {len}")
+        })
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in MAPS {
+        let mut cuts: Vec<u64> = (0..size).map(|_| rng.below(SPAN)).collect();
+        cuts.push(0);
+        cuts.push(SPAN);
+        cuts.sort();
+        cuts.dedup();
+
+        let pieces: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        let mut order: Vec<usize> = (0..pieces.len()).collect();
+        rng.shuffle(&mut order);
+        let mut dest = 0;
+        let mut dests = vec![0; pieces.len()];
+        for i in order {
+            dests[i] = dest;
+            dest += pieces[i].1;
+        }
+        let mut entries: Vec<(u64, (u64, u64))> = dests.into_iter().zip(pieces).collect();
+        entries.retain(|_| rng.chance(0.9));
+        rng.shuffle(&mut entries);
+
+        writeln!(input, "\n{name} map:").unwrap();
+        for (dest, (source, len)) in entries {
+            writeln!(input, "{dest} {source} {len}").unwrap();
+        }
+    }
+
+    input
+}
+
+/// `size` races, at most 4 so the concatenated part 2 race fits in 64 bits.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let races = size.min(4);
+    let mut times = String::from("Time:    ");
+    let mut records = String::from("Distance:");
+    for _ in 0..races {
+        let time = rng.range(7..=99) as u64;
+        let best = (time / 2) * (time - time / 2);
+        write!(times, " {time:>4}").unwrap();
+        write!(records, " {:>4}", rng.below(best)).unwrap();
+    }
+
+    format!("{times}\n{records}\n")
+}
+
+/// `size` hands.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    const CARDS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let hand: String = (0..5).map(|_| *rng.choose(&CARDS)).collect();
+        writeln!(input, "{hand} {}", rng.range(1..=1000)).unwrap();
+    }
+
+    input
+}
+
+/// A network whose instructions are `size` long.
+///
+/// Like the official input, each of the six ghosts loops back to its `Z` node
+/// after a whole number of passes through the instructions, so part 2 is the
+/// least common multiple of the loop lengths. Every node also has a branch
+/// the ghosts never take, pointing somewhere random.
+fn day08(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [usize; 9] = [43, 47, 53, 59, 61, 67, 71, 73, 79];
+
+    let directions: Vec<char> = (0..size).map(|_| *rng.choose(&['L', 'R'])).collect();
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    let mut next_label = 0;
+    let mut new_label = |end: char| {
+        next_label += 1;
+        format!("{}{end}", label(next_label).to_uppercase())
+    };
+
+    // Each ghost's nodes, from its start through to its end node.
+    let chains: Vec<Vec<String>> = primes[..6]
+        .iter()
+        .enumerate()
+        .map(|(ghost, prime)| {
+            let len = size * prime;
+            (0..=len)
+                .map(|i| match (ghost, i) {
+                    (0, 0) => "AAA".to_string(),
+                    (0, i) if i == len => "ZZZ".to_string(),
+                    (_, 0) => new_label('A'),
+                    (_, i) if i == len => new_label('Z'),
+                    _ => new_label('X'),
+                })
+                .collect()
+        })
+        .collect();
+    let all: Vec<&String> = chains.iter().flatten().collect();
+
+    let mut nodes = Vec::new();
+    for chain in &chains {
+        let len = chain.len() - 1;
+        for (i, node) in chain.iter().enumerate() {
+            let next = if i == len { &chain[1] } else { &chain[i + 1] };
+            let other = rng.choose(&all);
+            let (left, right) = if directions[i % size] == 'L' {
+                (next, *other)
+            } else {
+                (*other, next)
+            };
+            nodes.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+    rng.shuffle(&mut nodes);
+
+    let directions: String = directions.into_iter().collect();
+    format!("{directions}\n\n{}\n", nodes.join("\n"))
+}
+
+/// `size` histories, each sampled from a polynomial of degree at most 5.
+fn day09(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..=rng.range(0..=5)).map(|_| rng.signed(-9..=9)).collect();
+        let values: Vec<String> = (0..21)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, c| acc * x + c)
+                    .to_string()
+            })
+            .collect();
+        writeln!(input, "{}", values.join(" ")).unwrap();
+    }
+
+    input
+}
+
+/// Which of north, south, east and west a pipe connects to.
+type Connections = [bool; 4];
+
+/// The outline of a random tree on an `n` by `n` lattice.
+///
+/// The tree is drawn two cells per node, each cell `scale` units wide, and the
+/// outline is returned as the connections at each corner of a unit, a
+/// `(2n - 1) * scale + 1` square lattice. Because the drawing has no holes and
+/// never touches itself diagonally, the outline is always a single simple
+/// loop.
+fn tree_outline(rng: &mut Rng, n: usize, scale: usize) -> Vec<Vec<Connections>> {
+    let cells = 2 * n - 1;
+    let mut filled = vec![vec![false; cells]; cells];
+    let mut visited = vec![vec![false; n]; n];
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    visited[0][0] = true;
+    filled[0][0] = true;
+
+    while let Some(&(x, y)) = stack.last() {
+        let neighbors: Vec<(usize, usize)> = [(0, -1), (0, 1), (1, 0), (-1, 0)]
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let nx = x.checked_add_signed(dx).filter(|&nx| nx < n)?;
+                let ny = y.checked_add_signed(dy).filter(|&ny| ny < n)?;
+                (!visited[ny][nx]).then_some((nx, ny))
+            })
+            .collect();
+
+        if neighbors.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        // Leave some nodes out of the tree so its shape, and so the length of
+        // its outline, varies.
+        let (nx, ny) = *rng.choose(&neighbors);
+        visited[ny][nx] = true;
+        if rng.chance(0.2) {
+            continue;
+        }
+
+        filled[ny * 2][nx * 2] = true;
+        filled[y + ny][x + nx] = true;
+        stack.push((nx, ny));
+    }
+
+    let units = cells * scale;
+    let is_filled = |x: usize, y: usize, dx: isize, dy: isize| match (
+        x.checked_add_signed(dx),
+        y.checked_add_signed(dy),
+    ) {
+        (Some(x), Some(y)) if x < units && y < units => filled[y / scale][x / scale],
+        _ => false,
+    };
+
+    (0..=units)
+        .map(|y| {
+            (0..=units)
+                .map(|x| {
+                    [
+                        is_filled(x, y, -1, -1) != is_filled(x, y, 0, -1),
+                        is_filled(x, y, -1, 0) != is_filled(x, y, 0, 0),
+                        is_filled(x, y, 0, -1) != is_filled(x, y, 0, 0),
+                        is_filled(x, y, -1, -1) != is_filled(x, y, -1, 0),
+                    ]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A field of pipes roughly `size` tiles square, with a single loop through
+/// `S` surrounded by junk pipes.
+///
+/// The loop outlines a tree drawn at double scale, so it encloses tiles.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+    let n = (size.saturating_sub(1) / 4).max(2);
+    let outline = tree_outline(rng, n, 2);
+    let side = outline.len() + 2;
+
+    let mut grid = vec![vec!['.'; side]; side];
+    let mut on_loop = Vec::new();
+    for (y, row) in outline.iter().enumerate() {
+        for (x, connections) in row.iter().enumerate() {
+            let pipe = match connections {
+                [true, true, false, false] => '|',
+                [false, false, true, true] => '-',
+                [true, false, true, false] => 'L',
+                [true, false, false, true] => 'J',
+                [false, true, false, true] => '7',
+                [false, true, true, false] => 'F',
+                _ => continue,
+            };
+            grid[y + 1][x + 1] = pipe;
+            on_loop.push((x + 1, y + 1));
+        }
+    }
+
+    let (start_x, start_y) = *rng.choose(&on_loop);
+    for (y, row) in grid.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            let next_to_start = x.abs_diff(start_x) + y.abs_diff(start_y) <= 1;
+            if *tile == '.' && !next_to_start && rng.chance(0.3) {
+                *tile = *rng.choose(&PIPES);
+            }
+        }
+    }
+    grid[start_y][start_x] = 'S';
+
+    grid_to_string(&grid)
+}
+
+/// A `size` by `size` image, with some rows and columns left empty.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.08)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.08)).collect();
+    let grid: Vec<Vec<char>> = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if !empty_rows[y] && !empty_cols[x] && rng.chance(0.015) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    grid_to_string(&grid)
+}
+
+/// `size` condition records of up to 20 springs, with at most 14 unknown so
+/// the brute force in part 1 stays fast.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.range(6..=20);
+        let mut springs: Vec<char> = (0..len)
+            .map(|_| if rng.chance(0.4) { '#' } else { '.' })
+            .collect();
+        let broken = rng.range(0..=len - 1);
+        springs[broken] = '#';
+
+        let sizes: Vec<String> = springs
+            .split(|c| *c == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+
+        let mut unknown = 0;
+        for spring in &mut springs {
+            if unknown < 14 && rng.chance(0.35) {
+                *spring = '?';
+                unknown += 1;
+            }
+        }
+
+        let springs: String = springs.into_iter().collect();
+        writeln!(input, "{springs} {}", sizes.join(",")).unwrap();
+    }
+
+    input
+}
+
+/// `size` patterns, each with a perfect horizontal reflection and a vertical
+/// reflection that is off by a single smudge.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let mut patterns = Vec::new();
+    for _ in 0..size {
+        let width = rng.range(5..=17);
+        let height = rng.range(5..=17);
+        let mut grid: Vec<Vec<char>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        // Mirror columns about the line before column `b`.
+        let b = rng.range(1..=width - 1);
+        let reach = b.min(width - b);
+        for row in &mut grid {
+            for i in 0..reach {
+                row[b + i] = row[b - 1 - i];
+            }
+        }
+
+        // Mirror the first `2a` rows about the line before row `a`, leaving
+        // rows below them to hold the smudge.
+        let a = rng.range(1..=(height - 1) / 2);
+        for i in 0..a {
+            grid[a + i] = grid[a - 1 - i].clone();
+        }
+
+        let y = rng.range(2 * a..=height - 1);
+        let x = rng.range(b - reach..=b + reach - 1);
+        grid[y][x] = if grid[y][x] == '#' { '.' } else { '#' };
+
+        patterns.push(grid_to_string(&grid));
+    }
+
+    patterns.join("\n")
+}
+
+/// A `size` by `size` platform.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    random_grid(rng, size, size, &[('O', 0.2), ('#', 0.15)], '.')
+}
+
+/// `size` steps, drawn from a pool of labels so lenses get replaced and
+/// removed.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..(size / 5).max(1))
+        .map(|_| {
+            (0..rng.range(2..=6))
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(0.6) {
+                format!("{label}={}", rng.range(1..=9))
+            } else {
+                format!("{label}-")
+            }
+        })
+        .collect();
+
+    format!("{}\n", steps.join(","))
+}
+
+/// A `size` by `size` contraption.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    random_grid(
+        rng,
+        size,
+        size,
+        &[('|', 0.04), ('-', 0.04), ('/', 0.04), ('\\', 0.04)],
+        '.',
+    )
+}
+
+/// A `size` by `size` map of heat loss, at least 5 by 5 so that the ultra
+/// crucible, which moves four blocks at a time, can reach the far corner.
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let grid: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'1' + rng.below(9) as u8))
+                .collect()
+        })
+        .collect();
+
+    grid_to_string(&grid)
+}
+
+/// A dig plan tracing the outline of a random tree on a `size` by `size`
+/// lattice.
+///
+/// The lattice is stretched by different random amounts for the plan and for
+/// the hexadecimal instructions, so both describe simple loops of the same
+/// shape.
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let outline = tree_outline(rng, size.max(2), 1);
+    let side = outline.len();
+
+    let max_stretch = (0xfffff / side).min(100_000) as u64;
+    let mut stretch = |max: u64| {
+        let mut offsets = vec![0];
+        for _ in 1..side {
+            offsets.push(offsets.last().unwrap() + 1 + rng.below(max));
+        }
+        offsets
+    };
+    let (xs, ys) = (stretch(9), stretch(9));
+    let (hex_xs, hex_ys) = (stretch(max_stretch), stretch(max_stretch));
+
+    // Walk the loop from its top-left corner, which always turns east.
+    let start = (0..side)
+        .flat_map(|y| (0..side).map(move |x| (x, y)))
+        .find(|&(x, y)| outline[y][x].iter().any(|c| *c))
+        .unwrap();
+    // Each run is a direction, with the corners where it starts and ends.
+    type Corner = (usize, usize);
+    let mut runs: Vec<(usize, Corner, Corner)> = Vec::new();
+    let (mut pos, mut from) = (start, None);
+    loop {
+        let (x, y) = pos;
+        let connections = outline[y][x];
+        let dir = (0..4)
+            .find(|&dir| connections[dir] && Some(dir) != from)
+            .unwrap();
+        let next = match dir {
+            0 => (x, y - 1),
+            1 => (x, y + 1),
+            2 => (x + 1, y),
+            _ => (x - 1, y),
+        };
+
+        match runs.last_mut() {
+            Some((last, _, end)) if *last == dir => *end = next,
+            _ => runs.push((dir, pos, next)),
+        }
+
+        from = Some(dir ^ 1);
+        pos = next;
+        if pos == start {
+            break;
+        }
+    }
+
+    let mut input = String::new();
+    for (dir, (x1, y1), (x2, y2)) in runs {
+        let length = |offsets: &[u64]| {
+            if dir < 2 {
+                offsets[y1].abs_diff(offsets[y2])
+            } else {
+                offsets[x1].abs_diff(offsets[x2])
+            }
+        };
+        let (letter, digit) = match dir {
+            0 => ('U', 3),
+            1 => ('D', 1),
+            2 => ('R', 0),
+            _ => ('L', 2),
+        };
+        let (hex_offsets, offsets) = if dir < 2 {
+            (&hex_ys, &ys)
+        } else {
+            (&hex_xs, &xs)
+        };
+        writeln!(
+            input,
+            "{letter} {} (#{:05x}{digit})",
+            length(offsets),
+            length(hex_offsets)
+        )
+        .unwrap();
+    }
+
+    input
+}
+
+/// `size` workflows forming a deep tree under `in`, and `size` parts.
+fn day19(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..size)
+        .map(|i| if i == 0 { "in".to_string() } else { label(i) })
+        .collect();
+
+    // Mostly chain each workflow onto one of the few before it.
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); size];
+    for i in 1..size {
+        children[rng.range(i.saturating_sub(3)..=i - 1)].push(i);
+    }
+
+    let mut input = String::new();
+    for (label, children) in labels.iter().zip(children) {
+        let mut dests: Vec<String> = children.iter().map(|&i| labels[i].clone()).collect();
+        while dests.len() < 2 || (dests.len() < 4 && rng.chance(0.5)) {
+            dests.push(rng.choose(&["A", "R"]).to_string());
+        }
+        rng.shuffle(&mut dests);
+
+        let fallback = dests.pop().unwrap();
+        let rules: Vec<String> = dests
+            .into_iter()
+            .map(|dest| {
+                format!(
+                    "{}{}{}:{dest}",
+                    rng.choose(&['x', 'm', 'a', 's']),
+                    rng.choose(&['<', '>']),
+                    rng.range(1..=4000)
+                )
+            })
+            .collect();
+        writeln!(input, "{label}{{{},{fallback}}}", rules.join(",")).unwrap();
+    }
+
+    input.push('\n');
+    for _ in 0..size {
+        writeln!(
+            input,
+            "{{x={},m={},a={},s={}}}",
+            rng.range(1..=4000),
+            rng.range(1..=4000),
+            rng.range(1..=4000),
+            rng.range(1..=4000)
+        )
+        .unwrap();
+    }
+
+    input
+}
+
+/// Four `size`-bit counters feeding `rx`, as in the official input.
+///
+/// Each counter resets when it reaches an odd value with its top bit set, so
+/// `rx` first gets a low pulse after the least common multiple of those
+/// values.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let bits = size.clamp(2, 16);
+    let mut next_label = 0;
+    let mut new_label = || {
+        next_label += 1;
+        label(next_label)
+    };
+
+    let hub = new_label();
+    let mut modules = vec![format!("&{hub} -> rx")];
+    let mut starts = Vec::new();
+    for _ in 0..4 {
+        let value = (1 << (bits - 1)) | rng.below(1 << (bits - 1)) | 1;
+        let flip_flops: Vec<String> = (0..bits).map(|_| new_label()).collect();
+        let counter = new_label();
+        let inverter = new_label();
+
+        let mut resets = Vec::new();
+        for (i, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = Vec::new();
+            if let Some(next) = flip_flops.get(i + 1) {
+                outputs.push(next.clone());
+            }
+            if value & (1 << i) != 0 {
+                outputs.push(counter.clone());
+            }
+            if i == 0 || value & (1 << i) == 0 {
+                resets.push(flip_flop.clone());
+            }
+            modules.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+
+        resets.push(inverter.clone());
+        modules.push(format!("&{counter} -> {}", resets.join(", ")));
+        modules.push(format!("&{inverter} -> {hub}"));
+        starts.push(flip_flops[0].clone());
+    }
+
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut modules);
+
+    format!("{}\n", modules.join("\n"))
+}
+
+/// A `size` by `size` garden (rounded up to odd), with `S` in the middle and
+/// its row, column and the border clear of rocks.
+fn day21(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(3) | 1;
+    let middle = side / 2;
+    let grid: Vec<Vec<char>> = (0..side)
+        .map(|y| {
+            (0..side)
+                .map(|x| {
+                    if (x, y) == (middle, middle) {
+                        'S'
+                    } else if x == middle
+                        || y == middle
+                        || x == 0
+                        || y == 0
+                        || x == side - 1
+                        || y == side - 1
+                        || !rng.chance(0.12)
+                    {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    grid_to_string(&grid)
+}
+
+/// `size` bricks over a 10 by 10 area, snapshotted mid-fall in no particular
+/// order.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    let mut tops = [[0; 10]; 10];
+    let mut bricks = Vec::new();
+    for _ in 0..size {
+        let len = rng.range(0..=3);
+        let (dx, dy, dz) = match rng.below(3) {
+            0 => (len, 0, 0),
+            1 => (0, len, 0),
+            _ => (0, 0, len),
+        };
+        let x = rng.range(0..=9 - dx);
+        let y = rng.range(0..=9 - dy);
+
+        let mut z = 0;
+        for row in &tops[x..=x + dx] {
+            for top in &row[y..=y + dy] {
+                z = z.max(*top);
+            }
+        }
+        z += 1 + rng.range(0..=3);
+
+        for row in &mut tops[x..=x + dx] {
+            for top in &mut row[y..=y + dy] {
+                *top = z + dz;
+            }
+        }
+        bricks.push(format!("{x},{y},{z}~{},{},{}", x + dx, y + dy, z + dz));
+    }
+    rng.shuffle(&mut bricks);
+
+    format!("{}\n", bricks.join("\n"))
+}
+
+/// A trail map whose junctions form a `size` by `size` lattice, joined by
+/// corridors of random lengths with slopes leading away from the start.
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let junctions = size.max(2);
+    let mut offsets = || {
+        let mut offsets = vec![1];
+        for _ in 1..junctions {
+            offsets.push(offsets.last().unwrap() + rng.range(4..=12));
+        }
+        offsets
+    };
+    let (xs, ys) = (offsets(), offsets());
+    let (width, height) = (xs[junctions - 1] + 2, ys[junctions - 1] + 2);
+
+    let mut grid = vec![vec!['#'; width]; height];
+    grid[0][1] = '.';
+    grid[height - 1][width - 2] = '.';
+    for &y in &ys {
+        for &x in &xs {
+            grid[y][x] = '.';
+        }
+    }
+    for &y in &ys {
+        for pair in xs.windows(2) {
+            for tile in &mut grid[y][pair[0] + 1..pair[1]] {
+                *tile = '.';
+            }
+            grid[y][pair[0] + 1] = '>';
+            grid[y][pair[1] - 1] = '>';
+        }
+    }
+    for &x in &xs {
+        for pair in ys.windows(2) {
+            for row in &mut grid[pair[0] + 1..pair[1]] {
+                row[x] = '.';
+            }
+            grid[pair[0] + 1][x] = 'v';
+            grid[pair[1] - 1][x] = 'v';
+        }
+    }
+
+    grid_to_string(&grid)
+}
+
+/// `size` hailstones, all of which a single thrown rock would hit.
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let rock_pos: Vec<i64> = (0..3)
+        .map(|_| rng.signed(250_000_000_000_000..=350_000_000_000_000))
+        .collect();
+    let rock_vel: Vec<i64> = (0..3).map(|_| rng.signed(-300..=300)).collect();
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let time = rng.signed(1_000_000_000..=100_000_000_000);
+        let vel: Vec<i64> = rock_vel
+            .iter()
+            .map(|v| v + rng.signed(-500..=500))
+            .collect();
+        let pos: Vec<i64> = (0..3)
+            .map(|i| rock_pos[i] + (rock_vel[i] - vel[i]) * time)
+            .collect();
+        writeln!(
+            input,
+            "{}, {}, {} @ {}, {}, {}",
+            pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]
+        )
+        .unwrap();
+    }
+
+    input
+}
+
+/// A lowercase label of at least three letters, unique for each `n` and never
+/// clashing with the puzzles' own labels.
+fn label(n: usize) -> String {
+    let mut label = Vec::new();
+    let mut n = n;
+    while label.len() < 3 || n > 0 {
+        label.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    label.reverse();
+
+    String::from_utf8(label).unwrap()
+}
+
+/// A grid where each cell is one of `weights`' characters with its
+/// probability, or `background`.
+fn random_grid(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    weights: &[(char, f64)],
+    background: char,
+) -> String {
+    let grid: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| {
+                    let mut roll = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+                    for &(c, weight) in weights {
+                        if roll < weight {
+                            return c;
+                        }
+                        roll -= weight;
+                    }
+                    background
+                })
+                .collect()
+        })
+        .collect();
+
+    grid_to_string(&grid)
+}
+
+fn grid_to_string(grid: &[Vec<char>]) -> String {
+    let mut s = String::new();
+    for row in grid {
+        s.extend(row);
+        s.push('\n');
+    }
+
+    s
+}
@@ -1,7 +1,39 @@
-use std::{fmt::Display, num::TryFromIntError, ops};
-
+use std::{fmt::Display, num::TryFromIntError, ops, str::FromStr};
+
+use crate::error::{Error, Errors, Location};
+
+mod diff;
+mod image;
+mod iter;
+mod movement;
+mod neighbors;
+mod regions;
+mod render;
+mod sparse;
+mod tiled;
+mod transform;
+
+pub use diff::{Axis, Mismatch};
+pub use image::Palette;
+pub use iter::ColumnMut;
+pub use movement::MoveOutcome;
+pub use regions::{Component, Components, Connectivity};
+pub use render::{Color, Layer, Renderer};
+pub use sparse::SparseGrid;
+pub use tiled::{TiledGrid, Tiling};
+pub use transform::{Transform, View, ViewMut};
+
+/// A rectangular grid of cells, stored contiguously row by row.
+///
+/// Rows can be indexed as slices with `grid[y]`, so `grid[y][x]` works as it
+/// did when grids were nested vectors, and single cells with
+/// `grid[Position(x, y)]`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Grid<T>(pub Vec<Vec<T>>);
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Position(pub usize, pub usize);
@@ -24,6 +56,10 @@ pub enum Direction {
     South,
     East,
     West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -32,6 +68,10 @@ pub enum RelativeDirection {
     Backward,
     Left,
     Right,
+    /// Half of a left turn, 45° counterclockwise.
+    HalfLeft,
+    /// Half of a right turn, 45° clockwise.
+    HalfRight,
 }
 
 impl Actor {
@@ -42,12 +82,7 @@ impl Actor {
     }
 
     pub fn turn(&mut self, rel_dir: RelativeDirection) {
-        if let Some(dir) = self.vector.direction() {
-            let magnitude = self.vector.manhattan_distance();
-            self.vector = dir.turned(rel_dir).unit_vector() * magnitude;
-        } else {
-            unimplemented!("only cardinal vectors support turning at the moment")
-        }
+        self.vector = self.vector.rotated(rel_dir);
     }
 }
 
@@ -56,22 +91,63 @@ impl Vector {
         self.0.abs() + self.1.abs()
     }
 
+    /// The number of king moves this vector spans.
+    pub fn chebyshev_distance(&self) -> isize {
+        self.0.abs().max(self.1.abs())
+    }
+
+    /// The direction this vector points in, if it lies along one of the eight
+    /// compass directions.
     pub fn direction(&self) -> Option<Direction> {
-        if self.0 > 0 && self.1 == 0 {
-            Some(Direction::East)
-        } else if self.0 < 0 && self.1 == 0 {
-            Some(Direction::West)
-        } else if self.0 == 0 && self.1 < 0 {
-            Some(Direction::North)
-        } else if self.0 == 0 && self.1 > 0 {
-            Some(Direction::South)
+        if *self == Vector(0, 0) || (self.0 != 0 && self.1 != 0 && self.0.abs() != self.1.abs()) {
+            return None;
+        }
+
+        let unit = Vector(self.0.signum(), self.1.signum());
+        Direction::all8()
+            .iter()
+            .copied()
+            .find(|direction| direction.unit_vector() == unit)
+    }
+
+    /// This vector turned by `rel_dir`.
+    ///
+    /// Quarter and half turns are exact. For 45° turns the vector moves along
+    /// the square ring of vectors with the same Chebyshev distance, so compass
+    /// directions keep their length and two 45° turns make a 90° one.
+    pub fn rotated(self, rel_dir: RelativeDirection) -> Vector {
+        let r = self.chebyshev_distance();
+        if r == 0 {
+            return self;
+        }
+
+        // How far around the ring, clockwise from the corner at (r, -r).
+        let Vector(x, y) = self;
+        let t = if x == r && y < r {
+            y + r
+        } else if y == r && x > -r {
+            3 * r - x
+        } else if x == -r && y > -r {
+            5 * r - y
         } else {
-            None
+            7 * r + x
+        };
+
+        let t = (t + rel_dir.eighths() as isize * r).rem_euclid(8 * r);
+        if t < 2 * r {
+            Vector(r, t - r)
+        } else if t < 4 * r {
+            Vector(3 * r - t, r)
+        } else if t < 6 * r {
+            Vector(-r, 5 * r - t)
+        } else {
+            Vector(t - 7 * r, -r)
         }
     }
 }
 
 impl Direction {
+    /// The four cardinal directions.
     pub fn all() -> &'static [Direction; 4] {
         &[
             Direction::North,
@@ -81,68 +157,297 @@ impl Direction {
         ]
     }
 
+    /// Every direction, diagonals included, clockwise from north.
+    pub fn all8() -> &'static [Direction; 8] {
+        &[
+            Direction::North,
+            Direction::NorthEast,
+            Direction::East,
+            Direction::SouthEast,
+            Direction::South,
+            Direction::SouthWest,
+            Direction::West,
+            Direction::NorthWest,
+        ]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        matches!(
+            self,
+            Direction::NorthEast
+                | Direction::NorthWest
+                | Direction::SouthEast
+                | Direction::SouthWest
+        )
+    }
+
     pub fn unit_vector(&self) -> Vector {
         match self {
             Direction::North => Vector(0, -1),
             Direction::South => Vector(0, 1),
             Direction::East => Vector(1, 0),
             Direction::West => Vector(-1, 0),
+            Direction::NorthEast => Vector(1, -1),
+            Direction::NorthWest => Vector(-1, -1),
+            Direction::SouthEast => Vector(1, 1),
+            Direction::SouthWest => Vector(-1, 1),
         }
     }
 
     pub fn turned(self, direction: RelativeDirection) -> Self {
-        match direction {
-            RelativeDirection::Forward => self,
-            RelativeDirection::Backward => match self {
-                Direction::North => Direction::South,
-                Direction::South => Direction::North,
-                Direction::East => Direction::West,
-                Direction::West => Direction::East,
-            },
-            RelativeDirection::Left => match self {
-                Direction::North => Direction::West,
-                Direction::South => Direction::East,
-                Direction::East => Direction::North,
-                Direction::West => Direction::South,
-            },
-            RelativeDirection::Right => match self {
-                Direction::North => Direction::East,
-                Direction::South => Direction::West,
-                Direction::East => Direction::South,
-                Direction::West => Direction::North,
-            },
+        let all = Direction::all8();
+        let index = all.iter().position(|d| *d == self).unwrap();
+        all[(index + direction.eighths() as usize) % all.len()]
+    }
+}
+
+impl RelativeDirection {
+    /// How many eighths of a full turn clockwise this is.
+    pub fn eighths(self) -> u8 {
+        match self {
+            RelativeDirection::Forward => 0,
+            RelativeDirection::HalfRight => 1,
+            RelativeDirection::Right => 2,
+            RelativeDirection::Backward => 4,
+            RelativeDirection::Left => 6,
+            RelativeDirection::HalfLeft => 7,
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    /// Parses a direction written as `U`, `D`, `L` or `R`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::North),
+            "D" => Ok(Direction::South),
+            "L" => Ok(Direction::West),
+            "R" => Ok(Direction::East),
+            _ => Err(Error::invalid_token(s, s, "`U`, `D`, `L` or `R`")),
         }
     }
 }
 
 impl<T> Grid<T> {
-    pub fn new(inner: Vec<Vec<T>>) -> Grid<T> {
-        Grid(inner)
+    /// A `width` by `height` grid of `cells`, given row by row.
+    ///
+    /// Panics if there is not exactly one cell per position.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses a grid with one tile per character, skipping blank lines.
+    ///
+    /// Every character that is not a valid tile is reported at its line and
+    /// column, as is every row that is not as wide as the first. Input with
+    /// no rows at all is an error too.
+    pub fn parse(input: &str) -> Result<Grid<T>, Error>
+    where
+        T: TryFrom<char>,
+        T::Error: Into<Error>,
+    {
+        Grid::parse_with(input, |c| T::try_from(c).map_err(Into::into))
+    }
+
+    /// Like [`Grid::parse`], converting each character with `parse_tile`,
+    /// whose errors should be relative to the character.
+    pub fn parse_with(
+        input: &str,
+        mut parse_tile: impl FnMut(char) -> Result<T, Error>,
+    ) -> Result<Grid<T>, Error> {
+        Grid::parse_positions(input, |_, c| parse_tile(c))
+    }
+
+    /// Like [`Grid::parse_with`], but each marker character, such as an `S`
+    /// for the start, is replaced by the tile paired with it rather than
+    /// parsed. Returns where each marker was found, in the order given.
+    pub fn parse_with_markers<const N: usize>(
+        input: &str,
+        markers: [(char, T); N],
+        mut parse_tile: impl FnMut(char) -> Result<T, Error>,
+    ) -> Result<(Grid<T>, [Vec<Position>; N]), Error>
+    where
+        T: Clone,
+    {
+        let mut found: [Vec<Position>; N] = std::array::from_fn(|_| Vec::new());
+        let grid = Grid::parse_positions(input, |pos, c| {
+            match markers.iter().position(|(marker, _)| *marker == c) {
+                Some(m) => {
+                    found[m].push(pos);
+                    Ok(markers[m].1.clone())
+                }
+                None => parse_tile(c),
+            }
+        })?;
+
+        Ok((grid, found))
+    }
+
+    fn parse_positions(
+        input: &str,
+        mut parse_tile: impl FnMut(Position, char) -> Result<T, Error>,
+    ) -> Result<Grid<T>, Error> {
+        let mut errors = Errors::new();
+        let mut cells = Vec::new();
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let row_width = line.chars().count();
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                let location = Location::new(i + 1, width.min(row_width) + 1);
+                errors.push(if row_width < width {
+                    Error::MissingField {
+                        field: "tiles to fill out the row",
+                        location,
+                    }
+                } else {
+                    Error::InvalidToken {
+                        token: line.chars().skip(width).collect(),
+                        expected: "no more tiles than the first row",
+                        location,
+                    }
+                });
+                continue;
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let tile = parse_tile(Position(x, height), c)
+                    .map_err(|err| err.offset(Location::new(i + 1, x + 1)));
+                if let Some(tile) = errors.check(tile) {
+                    cells.push(tile);
+                }
+            }
+            height += 1;
+        }
+
+        let Some(width) = width else {
+            return Err(Error::missing_field(input, "at least one row of tiles"));
+        };
+
+        errors.finish(())?;
+        Ok(Grid::new(width, height, cells))
+    }
+
+    /// A grid holding `rows`, or `None` if they are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    /// A grid whose cells are given by calling `f` with each position.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid::new(width, height, cells)
+    }
+
+    /// A grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every cell, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Each row as a slice, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `max(1)` keeps `chunks_exact` happy for grids with no columns.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.0 < self.width && pos.1 < self.height
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.contains(pos).then(|| pos.1 * self.width + pos.0)
     }
 
     pub fn get_pos(&self, pos: Position) -> Option<&T> {
-        self.0.get(pos.1).and_then(|row| row.get(pos.0))
+        self.index_of(pos).map(|i| &self.cells[i])
     }
 
     pub fn get_pos_mut(&mut self, pos: Position) -> Option<&mut T> {
-        self.0.get_mut(pos.1).and_then(|row| row.get_mut(pos.0))
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Swaps the cells at `a` and `b`.
+    pub fn swap(&mut self, a: Position, b: Position) {
+        let a = self.index_of(a).expect("position is outside the grid");
+        let b = self.index_of(b).expect("position is outside the grid");
+        self.cells.swap(a, b);
     }
 
     pub fn wrapped_position(&self, signed_pos: SignedPosition) -> Position {
-        let y = (signed_pos.1.rem_euclid(self.len() as isize)) as usize;
-        let x = (signed_pos.0.rem_euclid(self[y].len() as isize)) as usize;
+        let y = (signed_pos.1.rem_euclid(self.height as isize)) as usize;
+        let x = (signed_pos.0.rem_euclid(self.width as isize)) as usize;
         Position(x, y)
     }
 }
 
 impl<T: Display> Display for Grid<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, row) in self.iter().enumerate() {
-            for cell in row.iter() {
-                write!(f, "{}", *cell)?;
+        for (i, row) in self.rows().enumerate() {
+            for cell in row {
+                write!(f, "{cell}")?;
             }
-            if i != self.len() - 1 {
-                writeln!(f, "")?;
+            if i != self.height - 1 {
+                writeln!(f)?;
             }
         }
 
@@ -150,16 +455,36 @@ impl<T: Display> Display for Grid<T> {
     }
 }
 
-impl<T> ops::Deref for Grid<T> {
-    type Target = Vec<Vec<T>>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl<T> ops::Index<usize> for Grid<T> {
+    type Output = [T];
+
+    /// Row `y`.
+    fn index(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
     }
 }
 
-impl<T> ops::DerefMut for Grid<T> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl<T> ops::IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> ops::Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get_pos(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> ops::IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_pos_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
     }
 }
 
@@ -204,10 +529,7 @@ impl ops::Sub<SignedPosition> for SignedPosition {
     type Output = Vector;
 
     fn sub(self, rhs: SignedPosition) -> Self::Output {
-        Vector(
-            self.0 as isize - rhs.0 as isize,
-            self.1 as isize - rhs.1 as isize,
-        )
+        Vector(self.0 - rhs.0, self.1 - rhs.1)
     }
 }
 
@@ -215,8 +537,8 @@ impl ops::Add<Vector> for SignedPosition {
     type Output = SignedPosition;
 
     fn add(self, rhs: Vector) -> Self::Output {
-        let x = self.0 as isize + rhs.0;
-        let y = self.1 as isize + rhs.1;
+        let x = self.0 + rhs.0;
+        let y = self.1 + rhs.1;
         SignedPosition(x, y)
     }
 }
@@ -225,8 +547,8 @@ impl ops::Sub<Vector> for SignedPosition {
     type Output = SignedPosition;
 
     fn sub(self, rhs: Vector) -> Self::Output {
-        let x = self.0 as isize - rhs.0;
-        let y = self.1 as isize - rhs.1;
+        let x = self.0 - rhs.0;
+        let y = self.1 - rhs.1;
         SignedPosition(x, y)
     }
 }
@@ -0,0 +1,73 @@
+use super::{Grid, Position};
+
+/// A line between two rows or two columns of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The line between rows `y - 1` and `y`.
+    Row(usize),
+    /// The line between columns `x - 1` and `x`.
+    Column(usize),
+}
+
+/// A position where two grids differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mismatch<'a, T> {
+    pub pos: Position,
+    /// The cell in the first grid, or `None` if it is too small to have one.
+    pub left: Option<&'a T>,
+    /// The cell in the second grid, or `None` if it is too small to have one.
+    pub right: Option<&'a T>,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Every position where this grid and `other` differ, row by row.
+    ///
+    /// Grids of different sizes are compared over the area either covers,
+    /// so every cell that only one of them has is a mismatch.
+    pub fn differences<'a>(
+        &'a self,
+        other: &'a Grid<T>,
+    ) -> impl Iterator<Item = Mismatch<'a, T>> + 'a {
+        let width = self.width().max(other.width());
+        let height = self.height().max(other.height());
+        (0..height)
+            .flat_map(move |y| (0..width).map(move |x| Position(x, y)))
+            .filter_map(|pos| {
+                let (left, right) = (self.get_pos(pos), other.get_pos(pos));
+                (left != right).then_some(Mismatch { pos, left, right })
+            })
+    }
+
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> Vec<Mismatch<'a, T>> {
+        self.differences(other).collect()
+    }
+
+    /// Like [`Grid::diff`], but stops after finding `limit` differences.
+    pub fn diff_up_to<'a>(&'a self, other: &'a Grid<T>, limit: usize) -> Vec<Mismatch<'a, T>> {
+        self.differences(other).take(limit).collect()
+    }
+
+    /// The pairs of positions mirrored across `axis` whose cells differ, the
+    /// one before the axis first.
+    ///
+    /// Only cells whose mirror image is within the grid are compared, so the
+    /// rows or columns beyond the nearer edge are ignored.
+    pub fn mirror_differences(
+        &self,
+        axis: Axis,
+    ) -> impl Iterator<Item = (Position, Position)> + '_ {
+        let (index, len, across) = match axis {
+            Axis::Row(y) => (y, self.height(), self.width()),
+            Axis::Column(x) => (x, self.width(), self.height()),
+        };
+        let reach = index.min(len.saturating_sub(index));
+
+        (0..reach)
+            .flat_map(move |k| (0..across).map(move |i| (k, i)))
+            .map(move |(k, i)| match axis {
+                Axis::Row(y) => (Position(i, y - 1 - k), Position(i, y + k)),
+                Axis::Column(x) => (Position(x - 1 - k, i), Position(x + k, i)),
+            })
+            .filter(|&(a, b)| self[a] != self[b])
+    }
+}
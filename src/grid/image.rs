@@ -0,0 +1,111 @@
+use std::io::{self, Write};
+
+use super::{Color, Grid, Layer, Position};
+
+/// Maps each tile of a grid to the color it is drawn in.
+///
+/// Any `Fn(&T) -> [u8; 3]` is a palette.
+pub trait Palette<T> {
+    /// The tile's color as red, green and blue.
+    fn rgb(&self, tile: &T) -> [u8; 3];
+
+    /// The tile's brightness in a grayscale image.
+    fn gray(&self, tile: &T) -> u8 {
+        luma(self.rgb(tile))
+    }
+}
+
+impl<T, F: Fn(&T) -> [u8; 3]> Palette<T> for F {
+    fn rgb(&self, tile: &T) -> [u8; 3] {
+        self(tile)
+    }
+}
+
+/// The perceived brightness of a color, per ITU-R BT.601.
+fn luma([r, g, b]: [u8; 3]) -> u8 {
+    ((299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000) as u8
+}
+
+impl Color {
+    /// A typical terminal's rendering of this color.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Red => [205, 49, 49],
+            Color::Green => [13, 188, 121],
+            Color::Yellow => [229, 229, 16],
+            Color::Blue => [36, 114, 200],
+            Color::Magenta => [188, 63, 188],
+            Color::Cyan => [17, 168, 205],
+            Color::White => [229, 229, 229],
+            Color::Gray => [102, 102, 102],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Writes this grid as a binary PPM image, with each cell a `scale` pixel
+    /// square colored by `palette`, or by the topmost of `overlays` that
+    /// covers it.
+    pub fn write_ppm(
+        &self,
+        out: impl Write,
+        palette: &impl Palette<T>,
+        scale: usize,
+        overlays: &[Layer],
+    ) -> io::Result<()> {
+        self.write_netpbm(out, "P6", scale, |pos| {
+            match overlays.iter().rev().find(|layer| layer.get(pos).is_some()) {
+                Some(layer) => layer.color.rgb().to_vec(),
+                None => palette.rgb(&self[pos]).to_vec(),
+            }
+        })
+    }
+
+    /// Like [`Grid::write_ppm`], but a binary PGM image in shades of gray.
+    pub fn write_pgm(
+        &self,
+        out: impl Write,
+        palette: &impl Palette<T>,
+        scale: usize,
+        overlays: &[Layer],
+    ) -> io::Result<()> {
+        self.write_netpbm(out, "P5", scale, |pos| {
+            match overlays.iter().rev().find(|layer| layer.get(pos).is_some()) {
+                Some(layer) => vec![luma(layer.color.rgb())],
+                None => vec![palette.gray(&self[pos])],
+            }
+        })
+    }
+
+    fn write_netpbm(
+        &self,
+        mut out: impl Write,
+        magic: &str,
+        scale: usize,
+        mut pixel: impl FnMut(Position) -> Vec<u8>,
+    ) -> io::Result<()> {
+        assert!(scale > 0, "cells must be at least one pixel");
+        write!(
+            out,
+            "{magic}\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        )?;
+
+        let mut row = Vec::new();
+        for y in 0..self.height() {
+            row.clear();
+            for x in 0..self.width() {
+                let pixel = pixel(Position(x, y));
+                for _ in 0..scale {
+                    row.extend_from_slice(&pixel);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&row)?;
+            }
+        }
+
+        out.flush()
+    }
+}
@@ -0,0 +1,100 @@
+use std::ops;
+
+use super::{Grid, Position};
+
+impl<T> Grid<T> {
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl DoubleEndedIterator<Item = Position> + ExactSizeIterator {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position(i % width, i / width))
+    }
+
+    /// Every cell and its position, row by row.
+    pub fn enumerate(&self) -> impl DoubleEndedIterator<Item = (Position, &T)> + ExactSizeIterator {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Each column, from left to right.
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator>
+           + ExactSizeIterator {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn column_mut(&mut self, x: usize) -> ColumnMut<'_, T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        ColumnMut {
+            cells: &mut self.cells[x..],
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// A column of a grid that can be written through, indexed by row.
+#[derive(Debug)]
+pub struct ColumnMut<'a, T> {
+    /// The grid's cells from the top of the column onward.
+    cells: &'a mut [T],
+    width: usize,
+    height: usize,
+}
+
+impl<T> ColumnMut<'_, T> {
+    pub fn len(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.height == 0
+    }
+
+    pub fn get(&self, y: usize) -> Option<&T> {
+        (y < self.height).then(|| &self.cells[y * self.width])
+    }
+
+    pub fn get_mut(&mut self, y: usize) -> Option<&mut T> {
+        (y < self.height).then(|| &mut self.cells[y * self.width])
+    }
+
+    /// Swaps the cells in rows `a` and `b`.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(
+            a < self.height && b < self.height,
+            "row is outside the grid"
+        );
+        self.cells.swap(a * self.width, b * self.width);
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.cells.iter().step_by(self.width)
+    }
+
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        self.cells.iter_mut().step_by(self.width)
+    }
+}
+
+impl<T> ops::Index<usize> for ColumnMut<'_, T> {
+    type Output = T;
+
+    /// The cell in row `y`.
+    fn index(&self, y: usize) -> &T {
+        self.get(y)
+            .unwrap_or_else(|| panic!("row {y} is outside the grid"))
+    }
+}
+
+impl<T> ops::IndexMut<usize> for ColumnMut<'_, T> {
+    fn index_mut(&mut self, y: usize) -> &mut T {
+        self.get_mut(y)
+            .unwrap_or_else(|| panic!("row {y} is outside the grid"))
+    }
+}
@@ -0,0 +1,59 @@
+use super::{Actor, Direction, Grid, Position, Vector};
+
+/// What happened when an actor tried to move on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoveOutcome {
+    /// The actor moved, and is still on the grid.
+    Moved,
+    /// The move would have taken the actor off the grid, past any of its
+    /// edges, so it stayed where it was.
+    HitBoundary,
+    /// The move took the actor off the grid on a grid that repeats, so it
+    /// came back on at the matching cell by the opposite edge, the same one
+    /// [`Grid::wrapping_neighbors4`] would give.
+    WouldWrap,
+}
+
+impl<T> Grid<T> {
+    /// The position `distance` cells from `pos` in `direction`, or `None` if
+    /// that is off the grid.
+    pub fn step(&self, pos: Position, direction: Direction, distance: usize) -> Option<Position> {
+        let offset = direction.unit_vector() * isize::try_from(distance).ok()?;
+        self.offset(pos, offset).ok()
+    }
+
+    /// Moves `actor` by its vector if that keeps it on the grid.
+    pub fn move_actor(&self, actor: &mut Actor) -> MoveOutcome {
+        match self.offset(actor.pos, actor.vector) {
+            Ok(pos) => {
+                actor.pos = pos;
+                MoveOutcome::Moved
+            }
+            Err(outcome) => outcome,
+        }
+    }
+
+    /// Moves `actor` by its vector as if the grid repeated in every
+    /// direction, wrapping it round to the opposite edge if it leaves.
+    pub fn move_actor_wrapping(&self, actor: &mut Actor) -> MoveOutcome {
+        match self.offset(actor.pos, actor.vector) {
+            Ok(pos) => {
+                actor.pos = pos;
+                MoveOutcome::Moved
+            }
+            Err(_) => {
+                actor.pos = self.wrapped_position(actor.pos.to_signed() + actor.vector);
+                MoveOutcome::WouldWrap
+            }
+        }
+    }
+
+    fn offset(&self, pos: Position, Vector(dx, dy): Vector) -> Result<Position, MoveOutcome> {
+        pos.0
+            .checked_add_signed(dx)
+            .zip(pos.1.checked_add_signed(dy))
+            .map(|(x, y)| Position(x, y))
+            .filter(|&pos| self.contains(pos))
+            .ok_or(MoveOutcome::HitBoundary)
+    }
+}
@@ -0,0 +1,42 @@
+use super::{Direction, Grid, Position, SignedPosition, Tiling};
+
+impl<T> Grid<T> {
+    /// The cells north, south, east and west of `pos` that are within the
+    /// grid, with the direction to each.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = (Direction, Position, &T)> {
+        Direction::all().iter().filter_map(move |&direction| {
+            let next = (pos + direction.unit_vector()).ok()?;
+            Some((direction, next, self.get_pos(next)?))
+        })
+    }
+
+    /// Like [`Grid::neighbors4`], but only the cells whose tile satisfies
+    /// `predicate`.
+    pub fn neighbors4_where(
+        &self,
+        pos: Position,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> impl Iterator<Item = (Direction, Position, &T)> {
+        self.neighbors4(pos)
+            .filter(move |(_, _, tile)| predicate(tile))
+    }
+
+    /// The cells surrounding `pos`, diagonals included, that are within the
+    /// grid, with the direction to each, clockwise from the north.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = (Direction, Position, &T)> {
+        Direction::all8().iter().filter_map(move |&direction| {
+            let next = (pos + direction.unit_vector()).ok()?;
+            Some((direction, next, self.get_pos(next)?))
+        })
+    }
+
+    /// The neighbors of `pos` in this grid repeated infinitely in every
+    /// direction, so there are always four. Each is given at its unwrapped
+    /// position, with the tile at the wrapped one.
+    pub fn wrapping_neighbors4(
+        &self,
+        pos: SignedPosition,
+    ) -> impl Iterator<Item = (Direction, SignedPosition, &T)> {
+        self.tiled(Tiling::Repeat).neighbors4(pos)
+    }
+}
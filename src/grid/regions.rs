@@ -0,0 +1,132 @@
+use super::{Direction, Grid, Position};
+
+/// Which cells count as neighbors when filling a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Only cells sharing an edge.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Connectivity::Four => Direction::all(),
+            Connectivity::Eight => Direction::all8(),
+        }
+    }
+}
+
+/// A connected region of cells found by [`Grid::label_components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub size: usize,
+    /// The top left corner of the smallest rectangle around the component.
+    pub min: Position,
+    /// The bottom right corner of that rectangle, inclusive.
+    pub max: Position,
+    /// Whether any of the component's cells are on the edge of the grid.
+    pub touches_border: bool,
+}
+
+/// The connected components of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The index in `components` of the component each cell belongs to, or
+    /// `None` for cells that are in none.
+    pub labels: Grid<Option<usize>>,
+    pub components: Vec<Component>,
+}
+
+impl Components {
+    /// The component the cell at `pos` belongs to, if any.
+    pub fn at(&self, pos: Position) -> Option<&Component> {
+        let id = (*self.labels.get_pos(pos)?)?;
+        Some(&self.components[id])
+    }
+}
+
+impl<T> Grid<T> {
+    /// Every position connected to `start` through cells satisfying
+    /// `predicate`, in the order they are reached, or none if `start` does
+    /// not satisfy it.
+    pub fn flood_fill(
+        &self,
+        start: Position,
+        connectivity: Connectivity,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Vec<Position> {
+        let mut seen = Grid::filled(self.width(), self.height(), false);
+        self.fill(start, connectivity, &mut predicate, &mut seen)
+    }
+
+    /// Splits the cells satisfying `predicate` into connected components,
+    /// numbered in the order their first cell appears row by row.
+    pub fn label_components(
+        &self,
+        connectivity: Connectivity,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> Components {
+        let mut seen = Grid::filled(self.width(), self.height(), false);
+        let mut labels = Grid::filled(self.width(), self.height(), None);
+        let mut components = Vec::new();
+
+        for start in self.positions() {
+            let region = self.fill(start, connectivity, &mut predicate, &mut seen);
+            if region.is_empty() {
+                continue;
+            }
+
+            let mut component = Component {
+                size: region.len(),
+                min: start,
+                max: start,
+                touches_border: false,
+            };
+            for pos in region {
+                labels[pos] = Some(components.len());
+                component.min = Position(component.min.0.min(pos.0), component.min.1.min(pos.1));
+                component.max = Position(component.max.0.max(pos.0), component.max.1.max(pos.1));
+                component.touches_border |= pos.0 == 0
+                    || pos.1 == 0
+                    || pos.0 == self.width() - 1
+                    || pos.1 == self.height() - 1;
+            }
+            components.push(component);
+        }
+
+        Components { labels, components }
+    }
+
+    /// Fills out from `start` with an explicit stack, skipping and marking
+    /// cells in `seen`.
+    fn fill(
+        &self,
+        start: Position,
+        connectivity: Connectivity,
+        predicate: &mut impl FnMut(&T) -> bool,
+        seen: &mut Grid<bool>,
+    ) -> Vec<Position> {
+        let mut region = Vec::new();
+        if seen.get_pos(start) != Some(&false) || !predicate(&self[start]) {
+            return region;
+        }
+
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            region.push(pos);
+            for &direction in connectivity.directions() {
+                if let Some(next) = self.step(pos, direction, 1) {
+                    if !seen[next] && predicate(&self[next]) {
+                        seen[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+
+        region
+    }
+}
@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    io::{self, IsTerminal},
+};
+
+use super::{Direction, Grid, Position};
+
+/// One of the basic ANSI terminal colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    /// The SGR parameter that sets this as the foreground color.
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::Gray => 90,
+        }
+    }
+}
+
+impl Direction {
+    /// An arrow pointing this way.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '↑',
+            Direction::South => '↓',
+            Direction::East => '→',
+            Direction::West => '←',
+            Direction::NorthEast => '↗',
+            Direction::NorthWest => '↖',
+            Direction::SouthEast => '↘',
+            Direction::SouthWest => '↙',
+        }
+    }
+}
+
+/// A named set of positions drawn over a grid, each with its own glyph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    pub name: String,
+    pub color: Color,
+    /// The glyph shown for the layer in the legend.
+    key: char,
+    glyphs: HashMap<Position, char>,
+}
+
+impl Layer {
+    /// A layer drawing `glyph` at each of `positions`.
+    pub fn new(
+        name: impl Into<String>,
+        glyph: char,
+        color: Color,
+        positions: impl IntoIterator<Item = Position>,
+    ) -> Layer {
+        Layer {
+            name: name.into(),
+            color,
+            key: glyph,
+            glyphs: positions.into_iter().map(|pos| (pos, glyph)).collect(),
+        }
+    }
+
+    /// A layer drawing an arrow at each step of a path, pointing the way the
+    /// path leaves it.
+    pub fn path(
+        name: impl Into<String>,
+        color: Color,
+        steps: impl IntoIterator<Item = (Position, Direction)>,
+    ) -> Layer {
+        let steps: Vec<_> = steps.into_iter().collect();
+        Layer {
+            name: name.into(),
+            color,
+            key: steps
+                .first()
+                .map_or('→', |(_, direction)| direction.arrow()),
+            glyphs: steps
+                .into_iter()
+                .map(|(pos, direction)| (pos, direction.arrow()))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, pos: Position) -> Option<char> {
+        self.glyphs.get(&pos).copied()
+    }
+}
+
+/// Draws a grid for a terminal, with layers drawn over its cells.
+///
+/// Colors are only used when standard output is a terminal, unless set
+/// otherwise with [`Renderer::color`].
+#[derive(Debug, Clone)]
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    layers: Vec<Layer>,
+    color: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn renderer(&self) -> Renderer<'_, T> {
+        Renderer {
+            grid: self,
+            layers: Vec::new(),
+            color: io::stdout().is_terminal(),
+        }
+    }
+}
+
+impl<T> Renderer<'_, T> {
+    /// Adds `layer` over those already added.
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Whether to color the layers with ANSI escape codes.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// A line per layer showing its glyph and name, topmost first.
+    pub fn legend(&self) -> String {
+        self.layers
+            .iter()
+            .rev()
+            .map(|layer| format!("{} {}", self.paint(layer.key, layer.color), layer.name))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn paint(&self, glyph: char, color: Color) -> String {
+        if self.color {
+            format!("\x1b[{}m{glyph}\x1b[0m", color.code())
+        } else {
+            glyph.to_string()
+        }
+    }
+}
+
+impl<T: Display> Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                let pos = Position(x, y);
+                let top = self
+                    .layers
+                    .iter()
+                    .rev()
+                    .find_map(|layer| Some((layer.get(pos)?, layer.color)));
+                match top {
+                    Some((glyph, color)) => write!(f, "{}", self.paint(glyph, color))?,
+                    None => write!(f, "{}", self.grid[pos])?,
+                }
+            }
+            if y != self.grid.height() - 1 {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
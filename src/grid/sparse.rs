@@ -0,0 +1,137 @@
+use std::{collections::HashMap, fmt::Display, ops};
+
+use super::{Grid, Position, SignedPosition, Vector};
+
+/// An unbounded grid that only stores the cells that have been set, with
+/// every other cell holding a default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<SignedPosition, T>,
+    default: T,
+    bounds: Option<(SignedPosition, SignedPosition)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// An empty grid, where every cell is `default`.
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// A sparse copy of `grid` with its top left corner at `offset`, and
+    /// `default` everywhere outside it.
+    pub fn from_grid(grid: Grid<T>, offset: SignedPosition, default: T) -> SparseGrid<T> {
+        let width = grid.width();
+        let mut sparse = SparseGrid::new(default);
+        for (i, cell) in grid.cells.into_iter().enumerate() {
+            let pos = Position(i % width, i / width);
+            sparse.insert(pos.to_signed() + Vector(offset.0, offset.1), cell);
+        }
+
+        sparse
+    }
+
+    /// The top left and bottom right corners, inclusive, of the smallest
+    /// rectangle around every cell ever set, or `None` if none have been.
+    ///
+    /// Bounds only grow: removing a cell does not shrink them.
+    pub fn bounds(&self) -> Option<(SignedPosition, SignedPosition)> {
+        self.bounds
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// How many cells have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cell at `pos`, or the default if it has not been set.
+    pub fn get(&self, pos: SignedPosition) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// Whether the cell at `pos` has been set.
+    pub fn contains(&self, pos: SignedPosition) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Sets the cell at `pos`, returning what was set there before.
+    pub fn insert(&mut self, pos: SignedPosition, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (pos, pos),
+            Some((min, max)) => (
+                SignedPosition(min.0.min(pos.0), min.1.min(pos.1)),
+                SignedPosition(max.0.max(pos.0), max.1.max(pos.1)),
+            ),
+        });
+
+        self.cells.insert(pos, value)
+    }
+
+    /// Resets the cell at `pos` to the default, returning what was set there.
+    pub fn remove(&mut self, pos: SignedPosition) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Every cell that has been set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (SignedPosition, &T)> {
+        self.cells.iter().map(|(pos, value)| (*pos, value))
+    }
+
+    /// A dense grid covering [`SparseGrid::bounds`], and the position of its
+    /// top left corner, so that `pos` here is `pos - offset` there.
+    pub fn to_grid(&self) -> (Grid<T>, SignedPosition)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return (Grid::new(0, 0, Vec::new()), SignedPosition(0, 0));
+        };
+
+        let width = (max.0 - min.0) as usize + 1;
+        let height = (max.1 - min.1) as usize + 1;
+        let grid = Grid::from_fn(width, height, |pos| {
+            self.get(pos.to_signed() + Vector(min.0, min.1)).clone()
+        });
+
+        (grid, min)
+    }
+}
+
+impl<T> ops::Index<SignedPosition> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: SignedPosition) -> &T {
+        self.get(pos)
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Draws the cells within the bounds, row by row.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                write!(f, "{}", self.get(SignedPosition(x, y)))?;
+            }
+            if y != max.1 {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
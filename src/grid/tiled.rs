@@ -0,0 +1,106 @@
+use super::{Direction, Grid, Position, SignedPosition};
+
+/// How copies of a grid are laid out to fill the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tiling {
+    /// Every tile is the same as the grid.
+    Repeat,
+    /// Neighboring tiles are mirror images, so that cells across each tile
+    /// edge match.
+    Mirror,
+}
+
+/// A view of a grid repeated infinitely in every direction.
+///
+/// The grid itself is the tile at `(0, 0)`. Panics on any lookup if the grid
+/// is empty.
+#[derive(Debug)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid<T>,
+    tiling: Tiling,
+}
+
+impl<T> Clone for TiledGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TiledGrid<'_, T> {}
+
+impl<T> Grid<T> {
+    /// This grid tiled across the plane.
+    pub fn tiled(&self, tiling: Tiling) -> TiledGrid<'_, T> {
+        TiledGrid { grid: self, tiling }
+    }
+}
+
+impl<'a, T> TiledGrid<'a, T> {
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    pub fn tiling(&self) -> Tiling {
+        self.tiling
+    }
+
+    /// Which tile `pos` is in, and where it is in the grid.
+    pub fn locate(&self, pos: SignedPosition) -> (SignedPosition, Position) {
+        let (width, height) = (self.grid.width() as isize, self.grid.height() as isize);
+        let tile = SignedPosition(pos.0.div_euclid(width), pos.1.div_euclid(height));
+        let mut local = self.grid.wrapped_position(pos);
+
+        if self.tiling == Tiling::Mirror {
+            if tile.0 % 2 != 0 {
+                local.0 = self.grid.width() - 1 - local.0;
+            }
+            if tile.1 % 2 != 0 {
+                local.1 = self.grid.height() - 1 - local.1;
+            }
+        }
+
+        (tile, local)
+    }
+
+    /// The top left corner of `tile`.
+    pub fn tile_origin(&self, tile: SignedPosition) -> SignedPosition {
+        SignedPosition(
+            tile.0 * self.grid.width() as isize,
+            tile.1 * self.grid.height() as isize,
+        )
+    }
+
+    pub fn get(&self, pos: SignedPosition) -> &'a T {
+        &self.grid[self.locate(pos).1]
+    }
+
+    /// The cells north, south, east and west of `pos`, crossing into
+    /// neighboring tiles where needed, with the direction to each.
+    pub fn neighbors4(
+        &self,
+        pos: SignedPosition,
+    ) -> impl Iterator<Item = (Direction, SignedPosition, &'a T)> {
+        self.neighbors(pos, Direction::all())
+    }
+
+    /// Like [`TiledGrid::neighbors4`], diagonals included, clockwise from the
+    /// north.
+    pub fn neighbors8(
+        &self,
+        pos: SignedPosition,
+    ) -> impl Iterator<Item = (Direction, SignedPosition, &'a T)> {
+        self.neighbors(pos, Direction::all8())
+    }
+
+    fn neighbors(
+        &self,
+        pos: SignedPosition,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = (Direction, SignedPosition, &'a T)> {
+        let tiles = *self;
+        directions.iter().map(move |&direction| {
+            let next = pos + direction.unit_vector();
+            (direction, next, tiles.get(next))
+        })
+    }
+}
@@ -0,0 +1,287 @@
+use std::ops;
+
+use super::{Direction, Grid, Position, RelativeDirection, Vector};
+
+/// A rotation or reflection of a grid.
+///
+/// Positions and directions can be mapped through a transform and back, so
+/// work done on a transformed grid can be translated to the original frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    /// A quarter turn clockwise, so the top row becomes the right column.
+    RotateCw,
+    /// A quarter turn counterclockwise, so the top row becomes the left
+    /// column.
+    RotateCcw,
+    /// A reflection across the main diagonal, swapping rows and columns.
+    Transpose,
+    /// A reflection left to right.
+    FlipHorizontal,
+    /// A reflection top to bottom.
+    FlipVertical,
+}
+
+impl Transform {
+    /// The transform that undoes this one.
+    pub fn inverse(self) -> Transform {
+        match self {
+            Transform::RotateCw => Transform::RotateCcw,
+            Transform::RotateCcw => Transform::RotateCw,
+            t => t,
+        }
+    }
+
+    /// Whether this transform swaps the width and height of a grid.
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Transform::RotateCw | Transform::RotateCcw | Transform::Transpose
+        )
+    }
+
+    /// The width and height of a `width` by `height` grid after this
+    /// transform.
+    pub fn dimensions(self, width: usize, height: usize) -> (usize, usize) {
+        if self.swaps_axes() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Where the cell at `pos` in a `width` by `height` grid ends up.
+    pub fn position(self, pos: Position, width: usize, height: usize) -> Position {
+        let Position(x, y) = pos;
+        match self {
+            Transform::Identity => pos,
+            Transform::RotateCw => Position(height - 1 - y, x),
+            Transform::RotateCcw => Position(y, width - 1 - x),
+            Transform::Transpose => Position(y, x),
+            Transform::FlipHorizontal => Position(width - 1 - x, y),
+            Transform::FlipVertical => Position(x, height - 1 - y),
+        }
+    }
+
+    /// Which way `direction` points after this transform.
+    pub fn direction(self, direction: Direction) -> Direction {
+        match self {
+            Transform::Identity => direction,
+            Transform::RotateCw => direction.turned(RelativeDirection::Right),
+            Transform::RotateCcw => direction.turned(RelativeDirection::Left),
+            Transform::Transpose => reflected(direction, |Vector(x, y)| Vector(y, x)),
+            Transform::FlipHorizontal => reflected(direction, |Vector(x, y)| Vector(-x, y)),
+            Transform::FlipVertical => reflected(direction, |Vector(x, y)| Vector(x, -y)),
+        }
+    }
+}
+
+fn reflected(direction: Direction, reflect: impl Fn(Vector) -> Vector) -> Direction {
+    reflect(direction.unit_vector())
+        .direction()
+        .expect("reflecting a unit vector gives a unit vector")
+}
+
+/// A read-only view of a grid through a [`Transform`], without copying it.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    transform: Transform,
+}
+
+/// A mutable view of a grid through a [`Transform`], without copying it.
+#[derive(Debug)]
+pub struct ViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    transform: Transform,
+}
+
+impl<T> Grid<T> {
+    /// This grid as it would look after `transform`.
+    pub fn view(&self, transform: Transform) -> View<'_, T> {
+        View {
+            grid: self,
+            transform,
+        }
+    }
+
+    /// This grid as it would look after `transform`, with writes going
+    /// through to the grid.
+    pub fn view_mut(&mut self, transform: Transform) -> ViewMut<'_, T> {
+        ViewMut {
+            grid: self,
+            transform,
+        }
+    }
+
+    /// A copy of this grid with `transform` applied.
+    pub fn transformed(&self, transform: Transform) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(transform).to_grid()
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::RotateCw)
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::RotateCcw)
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Transpose)
+    }
+
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::FlipVertical)
+    }
+}
+
+/// Where `pos` in a view of `grid` through `transform` is in the grid itself.
+fn original_position<T>(grid: &Grid<T>, transform: Transform, pos: Position) -> Position {
+    let (width, height) = transform.dimensions(grid.width(), grid.height());
+    transform.inverse().position(pos, width, height)
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    pub fn width(&self) -> usize {
+        self.transform
+            .dimensions(self.grid.width(), self.grid.height())
+            .0
+    }
+
+    pub fn height(&self) -> usize {
+        self.transform
+            .dimensions(self.grid.width(), self.grid.height())
+            .1
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.0 < self.width() && pos.1 < self.height()
+    }
+
+    /// Where `pos` in this view is in the underlying grid.
+    pub fn original_position(&self, pos: Position) -> Position {
+        original_position(self.grid, self.transform, pos)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&'a T> {
+        if self.contains(pos) {
+            self.grid.get_pos(self.original_position(pos))
+        } else {
+            None
+        }
+    }
+
+    /// A new grid with the cells as they appear in this view.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width(), self.height(), |pos| self[pos].clone())
+    }
+}
+
+impl<T> ops::Index<Position> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the view"))
+    }
+}
+
+impl<T> ViewMut<'_, T> {
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    pub fn width(&self) -> usize {
+        self.transform
+            .dimensions(self.grid.width(), self.grid.height())
+            .0
+    }
+
+    pub fn height(&self) -> usize {
+        self.transform
+            .dimensions(self.grid.width(), self.grid.height())
+            .1
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.0 < self.width() && pos.1 < self.height()
+    }
+
+    /// Where `pos` in this view is in the underlying grid.
+    pub fn original_position(&self, pos: Position) -> Position {
+        original_position(self.grid, self.transform, pos)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if self.contains(pos) {
+            self.grid.get_pos(self.original_position(pos))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            let pos = self.original_position(pos);
+            self.grid.get_pos_mut(pos)
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the cells at `a` and `b`, both positions in this view.
+    pub fn swap(&mut self, a: Position, b: Position) {
+        assert!(
+            self.contains(a) && self.contains(b),
+            "position is outside the view"
+        );
+        let (a, b) = (self.original_position(a), self.original_position(b));
+        self.grid.swap(a, b);
+    }
+}
+
+impl<T> ops::Index<Position> for ViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the view"))
+    }
+}
+
+impl<T> ops::IndexMut<Position> for ViewMut<'_, T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the view"))
+    }
+}
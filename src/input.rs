@@ -0,0 +1,50 @@
+use std::str::FromStr;
+
+use crate::error::{Error, Errors, Location, Result};
+
+/// Parses every non-blank line of `input` with `parse_line`, moving any error
+/// onto the line it came from. Every line is parsed even if an earlier one
+/// fails, and all of their errors are reported.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T>,
+) -> Result<Vec<T>> {
+    let mut errors = Errors::new();
+    let values = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .filter_map(|(i, line)| errors.check(parse_line(line).map_err(|err| err.on_line(i + 1))))
+        .collect();
+
+    errors.finish(values)
+}
+
+/// An error for a single character that is not one of the `expected` tiles,
+/// relative to the character itself.
+pub fn invalid_tile(c: char, expected: &'static str) -> Error {
+    Error::InvalidToken {
+        token: c.to_string(),
+        expected,
+        location: Location::new(1, 1),
+    }
+}
+
+/// Parses `token`, a subslice of `text`, reporting it as an invalid token
+/// within `text` if it cannot be parsed.
+pub fn parse_token<T: FromStr>(text: &str, token: &str, expected: &'static str) -> Result<T> {
+    token
+        .parse()
+        .map_err(|_| Error::invalid_token(text, token, expected))
+}
+
+/// Takes the next field from `fields`, reporting it as missing from `text`.
+pub fn next_field<'a>(
+    text: &str,
+    fields: &mut impl Iterator<Item = &'a str>,
+    field: &'static str,
+) -> Result<&'a str> {
+    fields
+        .next()
+        .ok_or_else(|| Error::missing_field(text, field))
+}
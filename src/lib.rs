@@ -1,6 +1,18 @@
 use std::{ops, str::FromStr};
 
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod generate;
 pub mod grid;
+pub mod input;
+pub mod polygon;
+pub mod report;
+pub mod search;
+pub mod solution;
+
+pub use error::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Point3D<T> {
@@ -179,27 +191,29 @@ where
     }
 }
 
+/// Parses the comma separated `x, y, z` components of a point or vector.
+fn parse_components<T: FromStr>(s: &str) -> Result<(T, T, T), Error> {
+    let mut pieces = s
+        .split(',')
+        .map(|piece| input::parse_token(s, piece.trim(), "a number"));
+    let mut next = |field| {
+        pieces
+            .next()
+            .ok_or_else(|| Error::missing_field(s, field))?
+    };
+
+    Ok((next("x")?, next("y")?, next("z")?))
+}
+
 impl<T> FromStr for Point3D<T>
 where
     T: FromStr,
 {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut pieces = s
-            .split(',')
-            .map(|s| T::from_str(&s.trim()).map_err(|_| format!("invalid point {s}")));
-        Ok(Point3D {
-            x: pieces
-                .next()
-                .ok_or_else(|| format!("not enough values in point {s}"))??,
-            y: pieces
-                .next()
-                .ok_or_else(|| format!("not enough values in point {s}"))??,
-            z: pieces
-                .next()
-                .ok_or_else(|| format!("not enough values in point {s}"))??,
-        })
+        let (x, y, z) = parse_components(s)?;
+        Ok(Point3D { x, y, z })
     }
 }
 
@@ -207,22 +221,10 @@ impl<T> FromStr for Vector3D<T>
 where
     T: FromStr,
 {
-    type Err = String;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut pieces = s
-            .split(',')
-            .map(|s| s.trim().parse().map_err(|_| format!("invalid point {s}")));
-        Ok(Vector3D {
-            x: pieces
-                .next()
-                .ok_or_else(|| format!("not enough values in point {s}"))??,
-            y: pieces
-                .next()
-                .ok_or_else(|| format!("not enough values in point {s}"))??,
-            z: pieces
-                .next()
-                .ok_or_else(|| format!("not enough values in point {s}"))??,
-        })
+        let (x, y, z) = parse_components(s)?;
+        Ok(Vector3D { x, y, z })
     }
 }
@@ -0,0 +1,257 @@
+//! Areas of closed paths on the integer lattice.
+//!
+//! A [`Polygon`] is built from its vertices or from the runs of a walk, and
+//! measures the region it encloses exactly, without visiting every point in
+//! it: the shoelace formula gives its area and Pick's theorem turns that into
+//! a count of the lattice points inside.
+
+use std::fmt;
+
+use crate::grid::{Direction, SignedPosition, Vector};
+
+/// A simple polygon whose vertices are lattice points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    /// The corners in order, without the first repeated at the end, and with
+    /// no three in a row along the same line.
+    vertices: Vec<SignedPosition>,
+}
+
+/// Why a path doesn't make a polygon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolygonError {
+    /// The path ends somewhere other than where it started.
+    Unclosed {
+        start: SignedPosition,
+        end: SignedPosition,
+    },
+    /// Two edges of the path cross or touch, or one doubles back over the
+    /// last.
+    SelfIntersecting {
+        edges: [(SignedPosition, SignedPosition); 2],
+    },
+    /// The path doesn't have enough corners to enclose anything.
+    TooFewVertices,
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonError::Unclosed { start, end } => write!(
+                f,
+                "path starting at {start:?} ends at {end:?} instead of closing"
+            ),
+            PolygonError::SelfIntersecting {
+                edges: [(a, b), (c, d)],
+            } => write!(f, "edge {a:?} to {b:?} intersects edge {c:?} to {d:?}"),
+            PolygonError::TooFewVertices => {
+                write!(f, "path has too few vertices to enclose an area")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolygonError {}
+
+impl Polygon {
+    /// The polygon traced by a closed path through `vertices`, whose last
+    /// vertex must be the same as its first.
+    ///
+    /// Vertices may be anywhere along an edge, not only at its corners, so a
+    /// path through every point of a loop works as well as its corners.
+    pub fn new(
+        vertices: impl IntoIterator<Item = SignedPosition>,
+    ) -> Result<Polygon, PolygonError> {
+        let mut vertices: Vec<SignedPosition> = vertices.into_iter().collect();
+        let (Some(&start), Some(&end)) = (vertices.first(), vertices.last()) else {
+            return Err(PolygonError::TooFewVertices);
+        };
+        if start != end {
+            return Err(PolygonError::Unclosed { start, end });
+        }
+
+        vertices.dedup();
+        vertices.pop();
+        let corners: Vec<_> = (0..vertices.len())
+            .filter(|&i| {
+                let prev = vertices[(i + vertices.len() - 1) % vertices.len()];
+                let next = vertices[(i + 1) % vertices.len()];
+                let (into, out_of) = (vertices[i] - prev, next - vertices[i]);
+                cross(into, out_of) != 0 || dot(into, out_of) < 0
+            })
+            .map(|i| vertices[i])
+            .collect();
+        if corners.len() < 3 {
+            // Fewer corners than a triangle either double back on themselves
+            // or never leave the start.
+            return match corners[..] {
+                [a, b] => Err(PolygonError::SelfIntersecting {
+                    edges: [(a, b), (b, a)],
+                }),
+                _ => Err(PolygonError::TooFewVertices),
+            };
+        }
+
+        let polygon = Polygon { vertices: corners };
+        polygon.check_simple()?;
+        Ok(polygon)
+    }
+
+    /// The polygon traced by walking each `(direction, length)` run in turn
+    /// from the origin, which the walk must end back at.
+    pub fn from_runs(
+        runs: impl IntoIterator<Item = (Direction, usize)>,
+    ) -> Result<Polygon, PolygonError> {
+        let start = SignedPosition(0, 0);
+        let mut vertices = vec![start];
+        let mut pos = start;
+        for (direction, length) in runs {
+            pos = pos + direction.unit_vector() * length as isize;
+            vertices.push(pos);
+        }
+
+        Polygon::new(vertices)
+    }
+
+    /// The corners, in the order they are visited.
+    pub fn vertices(&self) -> &[SignedPosition] {
+        &self.vertices
+    }
+
+    /// Twice the enclosed area, positive if the vertices run clockwise on a
+    /// grid whose y axis points down.
+    ///
+    /// Always exact, where half of it may not be a whole number.
+    pub fn doubled_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.0 as i128 * b.1 as i128 - b.0 as i128 * a.1 as i128)
+            .sum()
+    }
+
+    /// The enclosed area, rounded toward zero, with the sign of
+    /// [`Polygon::doubled_signed_area`].
+    ///
+    /// Only polygons with diagonal edges can have half an area left over.
+    pub fn signed_area(&self) -> i128 {
+        self.doubled_signed_area() / 2
+    }
+
+    /// How many lattice points lie on the edges, which is the number of unit
+    /// steps around the boundary when every edge is straight or diagonal.
+    pub fn boundary_len(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| {
+                let Vector(x, y) = b - a;
+                gcd(x.unsigned_abs() as i128, y.unsigned_abs() as i128)
+            })
+            .sum()
+    }
+
+    /// How many lattice points lie strictly inside, by Pick's theorem.
+    pub fn interior_points(&self) -> i128 {
+        (self.doubled_signed_area().abs() - self.boundary_len() + 2) / 2
+    }
+
+    /// How many lattice points lie inside or on the edges, which is the area
+    /// covered when each point is a whole grid cell.
+    pub fn total_area(&self) -> i128 {
+        self.interior_points() + self.boundary_len()
+    }
+
+    /// Each edge from its start to its end, the last closing back to the
+    /// first vertex.
+    fn edges(&self) -> impl Iterator<Item = (SignedPosition, SignedPosition)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(&a, &b)| (a, b))
+    }
+
+    /// Fails if any two edges that don't share a vertex meet, or if one edge
+    /// doubles back along the one before it.
+    fn check_simple(&self) -> Result<(), PolygonError> {
+        let edges: Vec<_> = self.edges().collect();
+        let n = edges.len();
+        for i in 0..n {
+            for j in i + 1..n {
+                let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+                let meet = if adjacent {
+                    let (first, second) = if j == i + 1 {
+                        (edges[i], edges[j])
+                    } else {
+                        (edges[j], edges[i])
+                    };
+                    doubles_back(first, second)
+                } else {
+                    segments_meet(edges[i], edges[j])
+                };
+                if meet {
+                    return Err(PolygonError::SelfIntersecting {
+                        edges: [edges[i], edges[j]],
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn cross(a: Vector, b: Vector) -> i128 {
+    a.0 as i128 * b.1 as i128 - a.1 as i128 * b.0 as i128
+}
+
+fn dot(a: Vector, b: Vector) -> i128 {
+    a.0 as i128 * b.0 as i128 + a.1 as i128 * b.1 as i128
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Whether `second`, which starts where `first` ends, runs back along it.
+fn doubles_back(
+    first: (SignedPosition, SignedPosition),
+    second: (SignedPosition, SignedPosition),
+) -> bool {
+    let (into, out_of) = (first.1 - first.0, second.1 - second.0);
+    cross(into, out_of) == 0 && dot(into, out_of) < 0
+}
+
+/// Whether the segments share any point, including their ends.
+fn segments_meet(
+    (a, b): (SignedPosition, SignedPosition),
+    (c, d): (SignedPosition, SignedPosition),
+) -> bool {
+    // Segments whose bounding boxes don't overlap can't meet, which rules
+    // out most pairs cheaply.
+    if a.0.max(b.0) < c.0.min(d.0)
+        || c.0.max(d.0) < a.0.min(b.0)
+        || a.1.max(b.1) < c.1.min(d.1)
+        || c.1.max(d.1) < a.1.min(b.1)
+    {
+        return false;
+    }
+
+    let side =
+        |p: SignedPosition, q: SignedPosition, r: SignedPosition| cross(q - p, r - p).signum();
+    let (d1, d2) = (side(a, b, c), side(a, b, d));
+    let (d3, d4) = (side(c, d, a), side(c, d, b));
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+
+    // Otherwise they only meet if an end of one lies on the other.
+    let on = |p: SignedPosition, q: SignedPosition, r: SignedPosition| {
+        side(p, q, r) == 0
+            && r.0 >= p.0.min(q.0)
+            && r.0 <= p.0.max(q.0)
+            && r.1 >= p.1.min(q.1)
+            && r.1 <= p.1.max(q.1)
+    };
+    on(a, b, c) || on(a, b, d) || on(c, d, a) || on(c, d, b)
+}
@@ -0,0 +1,271 @@
+use std::{
+    fmt::Write,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Error,
+    solution::{Answer, Part, Solution},
+};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Part N: answer` lines for reading in a terminal.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(Error::invalid_token(s, s, "`text`, `json` or `csv`")),
+        }
+    }
+}
+
+/// The outcome of solving one day, with how long each step took.
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    /// How long parsing took, if the input could be read at all.
+    pub parse: Option<Duration>,
+    pub result: Result<Vec<PartReport>, Error>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve: Duration,
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,parse_ns,solve_ns,status,error";
+
+/// Solves `parts` of the puzzle input, timing parsing and each part.
+pub fn report<S: Solution>(input: &str, parts: &[Part]) -> Report {
+    report_with::<S>(input, parts, |_| {})
+}
+
+/// Like [`report`], but calls `on_part` with each part as soon as it is
+/// solved, before starting on the next.
+pub fn report_with<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    mut on_part: impl FnMut(&PartReport),
+) -> Report {
+    let start = Instant::now();
+    let parsed = S::parse(input);
+    let parse = Some(start.elapsed());
+
+    let result = parsed.map(|parsed| {
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&parsed).into(),
+                    Part::Two => S::part2(&parsed).into(),
+                };
+                let part = PartReport {
+                    part,
+                    answer,
+                    solve: start.elapsed(),
+                };
+                on_part(&part);
+                part
+            })
+            .collect()
+    });
+
+    Report {
+        day: S::DAY,
+        parse,
+        result,
+    }
+}
+
+impl Report {
+    /// A day that could not be attempted, e.g. because its input is missing.
+    pub fn failed(day: u8, err: Error) -> Report {
+        Report {
+            day,
+            parse: None,
+            result: Err(err),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// A JSON object for this day.
+    ///
+    /// Answers are written as strings so that values wider than a double can
+    /// represent survive intact, and as `null` for unsolved parts.
+    pub fn to_json(&self) -> String {
+        let mut json = format!("{{\"day\":{},\"parse_ns\":", self.day);
+        write_json_duration(&mut json, self.parse);
+
+        match &self.result {
+            Ok(parts) => {
+                json.push_str(",\"status\":\"ok\",\"error\":null,\"parts\":[");
+                for (i, part) in parts.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    }
+                    let answer = match part.answer {
+                        Answer::Unsolved => "null".to_string(),
+                        ref answer => json_string(&answer.to_string()),
+                    };
+                    write!(
+                        json,
+                        "{{\"part\":{},\"answer\":{answer},\"solve_ns\":{}}}",
+                        part.part,
+                        part.solve.as_nanos()
+                    )
+                    .unwrap();
+                }
+                json.push_str("]}");
+            }
+            Err(err) => {
+                write!(
+                    json,
+                    ",\"status\":\"error\",\"error\":{},\"parts\":[]}}",
+                    json_string(&err.to_string())
+                )
+                .unwrap();
+            }
+        }
+
+        json
+    }
+
+    /// One CSV row per part, or one row without a part for each problem on
+    /// failure, matching [`CSV_HEADER`]. Unsolved parts have an empty answer.
+    pub fn csv_rows(&self) -> Vec<String> {
+        let parse = self
+            .parse
+            .map(|parse| parse.as_nanos().to_string())
+            .unwrap_or_default();
+
+        match &self.result {
+            Ok(parts) => parts
+                .iter()
+                .map(|part| {
+                    let answer = match part.answer {
+                        Answer::Unsolved => String::new(),
+                        ref answer => csv_field(&answer.to_string()),
+                    };
+                    format!(
+                        "{},{},{answer},{parse},{},ok,",
+                        self.day,
+                        part.part,
+                        part.solve.as_nanos()
+                    )
+                })
+                .collect(),
+            Err(err) => err
+                .as_slice()
+                .iter()
+                .map(|err| {
+                    format!(
+                        "{},,,{parse},,error,{}",
+                        self.day,
+                        csv_field(&err.to_string())
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A JSON array of every report.
+pub fn json_array(reports: &[Report]) -> String {
+    let objects: Vec<String> = reports.iter().map(Report::to_json).collect();
+    format!("[{}]", objects.join(","))
+}
+
+/// Prints a single day's report in `format`, with errors in text output going
+/// to stderr.
+pub fn print(report: &Report, format: Format) {
+    match format {
+        Format::Text => {
+            if let Ok(parts) = &report.result {
+                parts.iter().for_each(print_part);
+            }
+            print_outcome(report);
+        }
+        Format::Json => println!("{}", report.to_json()),
+        Format::Csv => {
+            println!("{CSV_HEADER}");
+            for row in report.csv_rows() {
+                println!("{row}");
+            }
+        }
+    }
+}
+
+/// Prints a `Part N: answer` line, or nothing for a part with no solution.
+pub fn print_part(part: &PartReport) {
+    if part.answer != Answer::Unsolved {
+        println!("Part {}: {}", part.part, part.answer);
+    }
+}
+
+/// Prints the rest of a text report once its parts have been printed with
+/// [`print_part`]: a note when no part was asked for, or every problem, to
+/// stderr.
+pub fn print_outcome(report: &Report) {
+    match &report.result {
+        Ok(parts) if parts.is_empty() => println!("Input ok"),
+        Ok(_) => {}
+        Err(err) => {
+            for err in err.as_slice() {
+                eprintln!("day {}: {err}", report.day);
+            }
+        }
+    }
+}
+
+fn write_json_duration(json: &mut String, duration: Option<Duration>) {
+    match duration {
+        Some(duration) => write!(json, "{}", duration.as_nanos()).unwrap(),
+        None => json.push_str("null"),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+
+    json
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
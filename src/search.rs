@@ -0,0 +1,190 @@
+//! Shortest path searches over any graph whose nodes can be hashed, such as
+//! positions on a grid or the states of a puzzle.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest way found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// Every state along the way, from the start to the goal inclusive.
+    pub states: Vec<S>,
+}
+
+/// What a search found, and how much work it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<S, C> {
+    /// The path to the first goal reached, or `None` if no goal is reachable.
+    pub path: Option<Path<S, C>>,
+    /// How many states had their successors generated.
+    pub expanded: usize,
+}
+
+/// Every state reached so far, by index, with the best known cost to it and
+/// the index of the state it was reached from.
+struct Visited<S, C> {
+    indices: HashMap<S, usize>,
+    states: Vec<(S, C, Option<usize>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new(start: S, cost: C) -> Visited<S, C> {
+        Visited {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![(start, cost, None)],
+        }
+    }
+
+    /// Records reaching `state` from `parent` at `cost`, if that is the first
+    /// or cheapest way found to it, and returns its index if so.
+    fn reach(
+        &mut self,
+        state: S,
+        cost: C,
+        parent: usize,
+        better: impl Fn(C, C) -> bool,
+    ) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let i = *entry.get();
+                if !better(cost, self.states[i].1) {
+                    return None;
+                }
+                self.states[i].1 = cost;
+                self.states[i].2 = Some(parent);
+                Some(i)
+            }
+            Entry::Vacant(entry) => {
+                let i = self.states.len();
+                self.states.push((entry.key().clone(), cost, Some(parent)));
+                entry.insert(i);
+                Some(i)
+            }
+        }
+    }
+
+    /// The path to the state at index `goal`, rebuilt by following parents.
+    fn path_to(&self, goal: usize) -> Path<S, C> {
+        let mut states = Vec::new();
+        let mut i = Some(goal);
+        while let Some(index) = i {
+            let (state, _, parent) = &self.states[index];
+            states.push(state.clone());
+            i = *parent;
+        }
+        states.reverse();
+
+        Path {
+            cost: self.states[goal].1,
+            states,
+        }
+    }
+}
+
+/// Breadth-first search from `start` to the nearest state satisfying
+/// `is_goal`, where every step costs one.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    let mut expanded = 0;
+
+    while let Some(i) = queue.pop_front() {
+        let (state, cost, _) = visited.states[i].clone();
+        if is_goal(&state) {
+            return Search {
+                path: Some(visited.path_to(i)),
+                expanded,
+            };
+        }
+
+        expanded += 1;
+        for next in successors(&state) {
+            // The first way found to a state is always a shortest one.
+            if let Some(j) = visited.reach(next, cost + 1, i, |_, _| false) {
+                queue.push_back(j);
+            }
+        }
+    }
+
+    Search {
+        path: None,
+        expanded,
+    }
+}
+
+/// Dijkstra's algorithm from `start` to the cheapest state satisfying
+/// `is_goal`, where `successors` gives each neighboring state with the cost
+/// of moving to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search, like [`dijkstra`] but guided by `heuristic`, an estimate of the
+/// remaining cost from a state to the goal. The path found is only the
+/// cheapest if the heuristic never overestimates.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut visited = Visited::new(start, C::default());
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let (state, best, _) = visited.states[i].clone();
+        if cost > best {
+            // A cheaper way here was found after this one was queued.
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Search {
+                path: Some(visited.path_to(i)),
+                expanded,
+            };
+        }
+
+        expanded += 1;
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(j) = visited.reach(next, next_cost, i, |new, old| new < old) {
+                heap.push(Reverse((estimate, next_cost, j)));
+            }
+        }
+    }
+
+    Search {
+        path: None,
+        expanded,
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use crate::{
+    bench::{self, Timings},
+    error::Error,
+    report::{self, Format, PartReport, Report},
+};
+
+/// A single day's puzzle, split into parsing and the two parts.
+///
+/// Implementors are unit structs; all state lives in the parsed input so each
+/// part can be run on its own.
+pub trait Solution {
+    /// The day of the calendar this solves.
+    const DAY: u8;
+
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::invalid_token(s, s, "`1` or `2`")),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    /// The part has no solution implemented yet, or the input has no answer
+    /// to it.
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Unsigned, u128, u32, u64, usize, u128);
+answer_from!(Signed, i128, i32, i64, isize);
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unsolved
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+/// Which input to read and which parts to solve, as given on the command line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Where to read the puzzle input, `-` meaning stdin. Defaults to
+    /// whatever is piped into stdin, or else [`input_path`] for the day.
+    pub input: Option<PathBuf>,
+    /// The only part to solve, rather than both.
+    pub part: Option<Part>,
+    pub format: Format,
+    /// Only parse the input, reporting every problem with it, without solving
+    /// any part.
+    pub check: bool,
+}
+
+pub const RUN_USAGE: &str = "[--input <path>|-] [--part 1|2] [--format text|json|csv] [--check]";
+
+impl RunOptions {
+    /// Parses [`RUN_USAGE`], returning `None` for anything else.
+    pub fn parse(mut args: &[&str]) -> Option<RunOptions> {
+        let mut options = RunOptions::default();
+        loop {
+            args = match args {
+                [] => return Some(options),
+                ["--input", path, rest @ ..] => {
+                    options.input = Some(PathBuf::from(path));
+                    rest
+                }
+                ["--part", part, rest @ ..] => {
+                    options.part = Some(part.parse().ok()?);
+                    rest
+                }
+                ["--format", format, rest @ ..] => {
+                    options.format = format.parse().ok()?;
+                    rest
+                }
+                ["--check", rest @ ..] => {
+                    options.check = true;
+                    rest
+                }
+                _ => return None,
+            };
+        }
+    }
+
+    /// The parts that were asked for, in order, or none when checking.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            _ if self.check => Vec::new(),
+            Some(part) => vec![part],
+            None => Part::all().to_vec(),
+        }
+    }
+
+    /// Reads the puzzle input for `day`, from stdin if the input is `-` or
+    /// if no input was given and stdin isn't a terminal.
+    pub fn read_input(&self, day: u8) -> io::Result<String> {
+        match &self.input {
+            Some(path) if path == Path::new("-") => read_stdin(),
+            Some(path) => read_path(path),
+            None if !io::stdin().is_terminal() => {
+                // Nothing piped in, as when stdin is `/dev/null` under cron or
+                // CI, means the usual input file.
+                let input = read_stdin()?;
+                if input.is_empty() {
+                    read_day_input(day)
+                } else {
+                    Ok(input)
+                }
+            }
+            None => read_day_input(day),
+        }
+    }
+}
+
+/// Reads the puzzle input for `day` from [`input_path`].
+pub fn read_day_input(day: u8) -> io::Result<String> {
+    read_path(&input_path(day))
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+fn read_path(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+/// The conventional location of the puzzle input for `day`.
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/{day:02}.txt"))
+}
+
+/// Object-safe view of a [`Solution`], so days can be picked at runtime.
+pub trait AnySolution {
+    fn run(&self, input: &str) -> Result<(Answer, Answer), Error>;
+
+    /// Solves only `parts`, skipping the work for any other part, and times
+    /// each step.
+    fn report(&self, input: &str, parts: &[Part]) -> Report;
+
+    /// Like [`AnySolution::report`], calling `on_part` with each part as soon
+    /// as it is solved.
+    fn report_with(
+        &self,
+        input: &str,
+        parts: &[Part],
+        on_part: &mut dyn FnMut(&PartReport),
+    ) -> Report;
+
+    /// Times parsing and each part separately over `runs` runs.
+    fn bench(&self, input: &str, runs: usize) -> Result<Timings, Error>;
+}
+
+impl<S: Solution> AnySolution for S {
+    fn run(&self, input: &str) -> Result<(Answer, Answer), Error> {
+        run::<S>(input)
+    }
+
+    fn report(&self, input: &str, parts: &[Part]) -> Report {
+        report::report::<S>(input, parts)
+    }
+
+    fn report_with(
+        &self,
+        input: &str,
+        parts: &[Part],
+        on_part: &mut dyn FnMut(&PartReport),
+    ) -> Report {
+        report::report_with::<S>(input, parts, on_part)
+    }
+
+    fn bench(&self, input: &str, runs: usize) -> Result<Timings, Error> {
+        bench::time::<S>(input, runs)
+    }
+}
+
+pub fn run<S: Solution>(input: &str) -> Result<(Answer, Answer), Error> {
+    let input = S::parse(input)?;
+    Ok((S::part1(&input).into(), S::part2(&input).into()))
+}
+
+/// Entry point for the per-day binaries: solve the requested parts of the
+/// puzzle input and print them.
+pub fn main<S: Solution>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let Some(options) = RunOptions::parse(&args) else {
+        eprintln!("usage: {:02} {RUN_USAGE}", S::DAY);
+        process::exit(1);
+    };
+
+    let report = match options.read_input(S::DAY) {
+        // Text answers are printed as each part is solved, so a slow part
+        // doesn't hold back the one before it.
+        Ok(input) if options.format == Format::Text => {
+            report::report_with::<S>(&input, &options.parts(), report::print_part)
+        }
+        Ok(input) => report::report::<S>(&input, &options.parts()),
+        Err(err) => Report::failed(S::DAY, err.into()),
+    };
+    match options.format {
+        Format::Text => report::print_outcome(&report),
+        format => report::print(&report, format),
+    }
+    if !report.is_ok() {
+        process::exit(1);
+    }
+}
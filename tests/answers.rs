@@ -0,0 +1,73 @@
+use std::{env, fs, process};
+
+use adventofcode_2023::{
+    answers::{Answers, Verdict},
+    error::Location,
+    solution::{Answer, Part},
+};
+
+#[test]
+fn answers_round_trip_through_text() {
+    let text = "# recorded by aoc record\n1 1 55208\n\n01 2 54578\n24 1 -3\n";
+    let answers: Answers = text.parse().unwrap();
+    assert_eq!(answers.get(1, Part::One), Some("55208"));
+    assert_eq!(answers.get(1, Part::Two), Some("54578"));
+    assert_eq!(answers.get(24, Part::One), Some("-3"));
+    assert_eq!(answers.get(24, Part::Two), None);
+
+    let saved = answers.to_string();
+    assert_eq!(saved, "01 1 55208\n01 2 54578\n24 1 -3\n");
+    assert_eq!(saved.parse::<Answers>().unwrap(), answers);
+}
+
+#[test]
+fn malformed_answers_are_reported_with_their_line() {
+    let err = "01 1 5\n01 3 7\n02 1\n".parse::<Answers>().unwrap_err();
+    let locations: Vec<_> = err
+        .as_slice()
+        .iter()
+        .filter_map(|err| err.location())
+        .collect();
+    // A part other than 1 or 2, and a line that ends before its answer.
+    assert_eq!(locations, [Location::new(2, 4), Location::new(3, 5)]);
+}
+
+#[test]
+fn answers_are_saved_and_loaded() {
+    let mut answers = Answers::default();
+    answers.insert(7, Part::Two, &Answer::Unsigned(u128::MAX));
+    answers.insert(9, Part::One, &Answer::Signed(-1));
+
+    let path = env::temp_dir().join(format!("aoc-answers-{}.txt", process::id()));
+    answers.save(&path).unwrap();
+    let loaded = Answers::load(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), answers);
+
+    assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+}
+
+#[test]
+fn verify_passes_fails_or_knows_nothing() {
+    let answers: Answers = "05 1 35\n".parse().unwrap();
+    assert_eq!(
+        answers.verify(5, Part::One, &Answer::Unsigned(35)),
+        Verdict::Pass
+    );
+    assert_eq!(
+        answers.verify(5, Part::One, &Answer::Signed(-35)),
+        Verdict::Fail {
+            expected: "35".to_string()
+        }
+    );
+    assert_eq!(
+        answers.verify(5, Part::Two, &Answer::Unsigned(46)),
+        Verdict::Unknown
+    );
+    assert_eq!(
+        answers.verify(5, Part::One, &Answer::Unsolved),
+        Verdict::Fail {
+            expected: "35".to_string()
+        }
+    );
+}
@@ -0,0 +1,94 @@
+use std::{env, fs, process, time::Duration};
+
+use adventofcode_2023::bench::{Baselines, Phase, Stats, Timings};
+
+fn nanos(samples: &[u64]) -> Vec<Duration> {
+    samples.iter().copied().map(Duration::from_nanos).collect()
+}
+
+fn timings(parse: u64, part1: u64, part2: u64) -> Timings {
+    let stats = |n| Stats::from_samples(nanos(&[n]));
+    Timings {
+        parse: stats(parse),
+        part1: stats(part1),
+        part2: stats(part2),
+    }
+}
+
+#[test]
+fn stats_summarize_samples_in_any_order() {
+    assert_eq!(
+        Stats::from_samples(nanos(&[30, 10, 20])),
+        Stats {
+            median: Duration::from_nanos(20),
+            min: Duration::from_nanos(10),
+            max: Duration::from_nanos(30),
+        }
+    );
+
+    // An even count has the mean of the middle two as its median.
+    assert_eq!(
+        Stats::from_samples(nanos(&[40, 10, 30, 20])),
+        Stats {
+            median: Duration::from_nanos(25),
+            min: Duration::from_nanos(10),
+            max: Duration::from_nanos(40),
+        }
+    );
+
+    let seven = Duration::from_nanos(7);
+    assert_eq!(
+        Stats::from_samples(vec![seven]),
+        Stats {
+            median: seven,
+            min: seven,
+            max: seven,
+        }
+    );
+}
+
+#[test]
+fn regressions_are_flagged_only_past_the_threshold() {
+    let mut baselines = Baselines::default();
+    baselines.insert(5, &timings(100, 1000, 0));
+    let regression =
+        |phase, median| baselines.regression(5, phase, Duration::from_nanos(median), 10.0);
+
+    assert_eq!(regression(Phase::Parse, 109), None);
+    assert_eq!(regression(Phase::Parse, 110), None);
+    assert_eq!(regression(Phase::Parse, 111), Some(11.0));
+    assert_eq!(regression(Phase::Part1, 1101), Some(10.1));
+    assert_eq!(regression(Phase::Part1, 500), None);
+
+    // A zero baseline or an unknown day can't be compared against.
+    assert_eq!(regression(Phase::Part2, 1_000_000), None);
+    assert_eq!(
+        baselines.regression(6, Phase::Parse, Duration::from_secs(1), 10.0),
+        None
+    );
+}
+
+#[test]
+fn baselines_round_trip_through_a_file() {
+    let text = "# saved by aoc bench --save\n05 parse 100\n\n05 part1 2500\n12 part2 7\n";
+    let baselines: Baselines = text.parse().unwrap();
+    assert_eq!(
+        baselines.get(5, Phase::Part1),
+        Some(Duration::from_nanos(2500))
+    );
+    assert_eq!(baselines.get(5, Phase::Part2), None);
+    assert_eq!(
+        baselines.to_string(),
+        "05 parse 100\n05 part1 2500\n12 part2 7\n"
+    );
+
+    let path = env::temp_dir().join(format!("aoc-baselines-{}.txt", process::id()));
+    baselines.save(&path).unwrap();
+    let loaded = Baselines::load(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), baselines);
+
+    assert_eq!(Baselines::load(&path).unwrap(), Baselines::default());
+    assert!("05 lunch 100\n".parse::<Baselines>().is_err());
+    assert!("05 parse\n".parse::<Baselines>().is_err());
+}
@@ -0,0 +1,126 @@
+use adventofcode_2023::{
+    days,
+    error::{Error, Location},
+    solution::{Part, RunOptions},
+};
+
+/// The locations of every problem `--check` finds in `input` for `day`.
+fn problems(day: u8, input: &str) -> Vec<Location> {
+    let report = days::get(day).unwrap().report(input, &[]);
+    match report.result {
+        Ok(_) => Vec::new(),
+        Err(err) => err.into_vec().iter().filter_map(Error::location).collect(),
+    }
+}
+
+#[test]
+fn check_mode_solves_nothing() {
+    let options = RunOptions::parse(&["--part", "1", "--check"]).unwrap();
+    assert!(options.check);
+    assert_eq!(options.parts(), Vec::<Part>::new());
+
+    let report = days::get(1).unwrap().report("1abc2\n", &options.parts());
+    assert!(report.result.unwrap().is_empty());
+}
+
+#[test]
+fn every_malformed_line_is_reported() {
+    assert_eq!(
+        problems(2, "Game 1: 3 blue\nGame x: 1 red\nGame 3 4 red\n"),
+        [Location::new(2, 6), Location::new(3, 13)]
+    );
+    assert_eq!(
+        problems(5, "seeds: 1 x\n\na map:\n1 2\n3 4 5\n6 y 7\n"),
+        [
+            Location::new(1, 10),
+            Location::new(4, 4),
+            Location::new(6, 3)
+        ]
+    );
+}
+
+#[test]
+fn every_invalid_tile_is_reported() {
+    assert_eq!(
+        problems(10, "S-7\n|x|\nL-?\n"),
+        [Location::new(2, 2), Location::new(3, 3)]
+    );
+    assert_eq!(
+        problems(13, "#.\n.a\n\n#b\n"),
+        [Location::new(2, 2), Location::new(4, 2)]
+    );
+    assert_eq!(problems(23, "#.#\n#z#\n#.#\n"), [Location::new(2, 2)]);
+    assert_eq!(
+        problems(14, "O.#\n.\n#..O\n"),
+        [Location::new(2, 2), Location::new(3, 4)]
+    );
+}
+
+#[test]
+fn every_bad_field_of_a_dig_plan_line_is_reported() {
+    assert_eq!(
+        problems(18, "R 6 (#70c710)\nX 5 (#0dc57)\nU x 70c710\n"),
+        [
+            Location::new(2, 1),
+            Location::new(2, 7),
+            Location::new(3, 3),
+            Location::new(3, 5)
+        ]
+    );
+}
+
+#[test]
+fn every_bad_rule_is_reported() {
+    assert_eq!(
+        problems(19, "px{a?1:A,m<x:R,A}\nin{s<1:px,}\n\n{x=1,m=2,a=3,s=z}\n"),
+        [
+            Location::new(1, 5),
+            Location::new(1, 12),
+            Location::new(2, 12),
+            Location::new(4, 16)
+        ]
+    );
+}
+
+#[test]
+fn every_undefined_workflow_is_reported() {
+    assert_eq!(
+        problems(19, "px{a<1:qq,R}\nin{s<1:px,zz}\n\n{x=1,m=2,a=3,s=4}\n"),
+        [Location::new(1, 8), Location::new(2, 11)]
+    );
+    assert_eq!(
+        problems(19, "px{a<1:A,R}\n\n{x=1,m=2,a=3,s=4}\n"),
+        [Location::new(4, 1)]
+    );
+}
+
+#[test]
+fn a_trench_that_does_not_close_or_crosses_itself_is_reported() {
+    assert_eq!(
+        problems(18, "R 6 (#000020)\nD 5 (#000021)\n"),
+        [Location::new(3, 1), Location::new(3, 1)]
+    );
+    assert_eq!(
+        problems(
+            18,
+            "R 2 (#000020)\nD 2 (#000021)\nL 1 (#000012)\nU 3 (#000033)\n\
+             L 1 (#000012)\nD 1 (#000011)\n"
+        ),
+        [Location::new(4, 3), Location::new(4, 5)]
+    );
+}
+
+#[test]
+fn well_formed_input_without_an_answer_is_reported() {
+    assert_eq!(problems(10, "..F7\n.S-J\n....\n"), [Location::new(2, 2)]);
+    assert_eq!(problems(10, "S-7\n|.|\nL--\n"), [Location::new(1, 1)]);
+}
+
+#[test]
+fn every_unknown_node_is_reported() {
+    assert_eq!(
+        problems(8, "LR\n\nAAA = (BBB, ZZZ)\nBBB = (CCC, AAA)\n"),
+        [Location::new(3, 13), Location::new(4, 8)]
+    );
+    assert_eq!(problems(8, "\n\nAAA = (AAA, AAA)\n"), [Location::new(1, 1)]);
+}
@@ -0,0 +1,63 @@
+use std::{
+    env,
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+/// Runs `program` with `args` from a directory without an `inputs/`, piping
+/// `input` into it.
+fn pipe(program: &str, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(program)
+        .args(args)
+        .current_dir(env::temp_dir())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn per_day_binaries_read_piped_input() {
+    let output = pipe(env!("CARGO_BIN_EXE_01"), &["--part", "1"], "1abc2\n");
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Part 1: 12\n");
+}
+
+#[test]
+fn aoc_reads_piped_input_for_a_single_day() {
+    let output = pipe(env!("CARGO_BIN_EXE_aoc"), &["1", "--part", "1"], "1abc2\n");
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Part 1: 12\n");
+}
+
+#[test]
+fn empty_stdin_falls_back_to_the_input_file() {
+    let output = pipe(env!("CARGO_BIN_EXE_01"), &[], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("inputs/01.txt"));
+}
+
+#[test]
+fn unsolved_parts_are_left_out_of_text_output() {
+    let input = "in{x>1:A,R}\n\n{x=2,m=3,a=4,s=5}\n";
+    let output = pipe(env!("CARGO_BIN_EXE_19"), &[], input);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "Part 1: 14\n");
+
+    let output = pipe(env!("CARGO_BIN_EXE_aoc"), &["19", "--format", "csv"], input);
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .any(|row| row.starts_with("19,2,,")));
+}
@@ -0,0 +1,168 @@
+use std::str::FromStr;
+
+use adventofcode_2023::{
+    days::{
+        day05::{MapEntry, ValueRange},
+        day07::{Hand, HandResult},
+        day10::Day10,
+        day12::{Record, Spring},
+        day15::{Day15, Step},
+        day19::{Item, Workflow},
+        day20::{Module, Pulse},
+        day22::Cube,
+        day23::{draw_hike, longest_hike, Day23},
+        day24::{count_intersections, Hailstone},
+    },
+    solution::Solution,
+    Point3D, Vector3D,
+};
+
+#[test]
+fn value_range_intersection() {
+    let (inside, outside) = ValueRange::new(0, 10).intersection(&ValueRange::new(3, 5));
+    assert_eq!(inside, Some(ValueRange::new(3, 5)));
+    assert_eq!(outside, vec![ValueRange::new(0, 3), ValueRange::new(5, 10)]);
+
+    let (inside, outside) = ValueRange::new(0, 3).intersection(&ValueRange::new(3, 5));
+    assert_eq!(inside, None);
+    assert_eq!(outside, vec![ValueRange::new(0, 3)]);
+
+    let entry = MapEntry::new(52, 50, 48);
+    assert_eq!(entry.map(79), Some(81));
+    assert_eq!(entry.map(98), None);
+}
+
+#[test]
+fn hand_ordering_and_jokers() {
+    let hand = |s| Hand::from_str(s).unwrap();
+
+    assert_eq!(hand("KTJJT").result(), HandResult::TwoPair);
+    assert!(hand("KK677") > hand("KTJJT"));
+
+    let mut jokers = hand("KTJJT");
+    jokers.jokerize();
+    assert_eq!(jokers.result(), HandResult::FourOfAKind);
+    assert!(jokers > hand("QQQJA"));
+
+    let mut weak_joker = hand("JKKK2");
+    weak_joker.jokerize();
+    assert_eq!(weak_joker.result(), HandResult::FourOfAKind);
+    assert!(weak_joker < hand("QQQQ2"));
+    assert!(Hand::from_str("KK67").is_err());
+}
+
+#[test]
+fn record_arrangements() {
+    let springs = "?###????????"
+        .chars()
+        .map(|c| Spring::try_from(c).unwrap())
+        .collect();
+    assert_eq!(Record::new(springs, vec![3, 2, 1]).arrangements(), 10);
+}
+
+#[test]
+fn steps_parse_into_labels_and_focal_lengths() {
+    assert_eq!(
+        Day15::parse("rn=1,cm-\n").unwrap(),
+        [
+            Step {
+                text: "rn=1".to_string(),
+                label: "rn".to_string(),
+                focal_length: Some(1),
+            },
+            Step {
+                text: "cm-".to_string(),
+                label: "cm".to_string(),
+                focal_length: None,
+            },
+        ]
+    );
+    assert!(Day15::parse("rn=x,cm").is_err());
+}
+
+#[test]
+fn workflow_routes_items() {
+    let workflow = Workflow::from_str("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
+    assert_eq!(workflow.next(&Item::new(787, 2655, 1222, 2876)), "qkq");
+    assert_eq!(workflow.next(&Item::new(787, 2655, 2222, 2876)), "A");
+    assert_eq!(workflow.next(&Item::new(787, 655, 2222, 2876)), "rfg");
+}
+
+#[test]
+fn flip_flop_toggles_on_low_pulses() {
+    let mut module = Module::from_str("%a -> b").unwrap();
+    assert_eq!(module.receive_pulse("x".to_string(), Pulse::High), None);
+    assert_eq!(
+        module.receive_pulse("x".to_string(), Pulse::Low),
+        Some(Pulse::High)
+    );
+    assert_eq!(
+        module.receive_pulse("x".to_string(), Pulse::Low),
+        Some(Pulse::Low)
+    );
+}
+
+#[test]
+fn cube_support() {
+    let point = |x, y, z| Point3D { x, y, z };
+    let a = Cube::new(point(1, 0, 1), point(1, 2, 1));
+    let b = Cube::new(point(0, 0, 2), point(2, 0, 2));
+    let c = Cube::new(point(0, 2, 2), point(2, 2, 3));
+
+    assert!(a.is_supporting(&b));
+    assert!(a.is_supporting(&c));
+    assert!(!b.is_supporting(&c));
+    assert!(c.is_being_supported(&[b, a]));
+}
+
+#[test]
+fn hailstone_paths_cross() {
+    let hailstone = |pos: [f64; 3], vel: [f64; 3]| {
+        Hailstone::new(
+            Point3D {
+                x: pos[0],
+                y: pos[1],
+                z: pos[2],
+            },
+            Vector3D {
+                x: vel[0],
+                y: vel[1],
+                z: vel[2],
+            },
+        )
+    };
+    let hailstones = [
+        hailstone([19.0, 13.0, 30.0], [-2.0, 1.0, -2.0]),
+        hailstone([18.0, 19.0, 22.0], [-1.0, -1.0, -2.0]),
+    ];
+
+    assert_eq!(count_intersections(&hailstones, 7.0, 27.0), 1);
+    assert_eq!(count_intersections(&hailstones[..1], 7.0, 27.0), 0);
+}
+
+#[test]
+fn hike_is_drawn_over_the_trail() {
+    let grid = Day23::parse("#.###\n#...#\n###.#\n").unwrap();
+    assert_eq!(longest_hike(&grid, true), 4);
+    assert_eq!(
+        draw_hike(&grid, true).color(false).to_string(),
+        "#O###\n#OOO#\n###O#"
+    );
+}
+
+#[test]
+fn slopes_on_the_edge_lead_nowhere() {
+    let grid = Day23::parse("#.#\n#.>\n#v#\n").unwrap();
+    assert_eq!(longest_hike(&grid, true), 2);
+}
+
+#[test]
+fn pipe_loop_counts_steps_from_the_start() {
+    let map = Day10::parse("-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF\n").unwrap();
+    let steps = &map.pipe_loop.positions;
+    assert_eq!(map.pipe_loop.len, 8);
+    assert_eq!(steps.len(), 8);
+    assert_eq!(steps[&map.start], 0);
+    assert_eq!(steps.values().max(), Some(&7));
+    assert_eq!(map.pipe_loop.start_tile, 'F');
+}
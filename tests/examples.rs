@@ -0,0 +1,262 @@
+//! Every day's solver run against the worked examples from its puzzle, plus
+//! fixtures for inputs that have broken a solver before.
+
+use adventofcode_2023::{
+    days::{
+        self, day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+        day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11, day12::Day12,
+        day13::Day13, day14::Day14, day15::Day15, day16::Day16, day17::Day17, day18::Day18,
+        day19::Day19, day20::Day20, day21, day21::Day21, day22::Day22, day23::Day23, day24,
+        day24::Day24,
+    },
+    solution::{Answer, Solution},
+};
+
+fn parse<S: Solution>(input: &str) -> S::Input {
+    S::parse(input).expect("example should parse")
+}
+
+fn part1<S: Solution>(input: &str) -> S::Part1 {
+    S::part1(&parse::<S>(input))
+}
+
+fn part2<S: Solution>(input: &str) -> S::Part2 {
+    S::part2(&parse::<S>(input))
+}
+
+/// Checks both parts of a day against a single example.
+fn check<S: Solution>(input: &str, part1: S::Part1, part2: S::Part2)
+where
+    S::Part1: PartialEq + std::fmt::Debug,
+    S::Part2: PartialEq + std::fmt::Debug,
+{
+    let input = parse::<S>(input);
+    assert_eq!(S::part1(&input), part1, "part 1");
+    assert_eq!(S::part2(&input), part2, "part 2");
+}
+
+#[test]
+fn day01() {
+    assert_eq!(part1::<Day01>(include_str!("fixtures/day01a.txt")), 142);
+    assert_eq!(part2::<Day01>(include_str!("fixtures/day01b.txt")), 281);
+}
+
+#[test]
+fn day02() {
+    check::<Day02>(include_str!("fixtures/day02.txt"), 8, 2286);
+}
+
+#[test]
+fn day03() {
+    check::<Day03>(include_str!("fixtures/day03.txt"), 4361, 467835);
+}
+
+#[test]
+fn day03_number_at_end_of_line() {
+    check::<Day03>(include_str!("fixtures/day03_end_of_line.txt"), 12, 35);
+}
+
+#[test]
+fn day04() {
+    check::<Day04>(include_str!("fixtures/day04.txt"), 13, 30);
+}
+
+#[test]
+fn day05() {
+    check::<Day05>(include_str!("fixtures/day05.txt"), 35, 46);
+}
+
+#[test]
+fn day06() {
+    check::<Day06>(include_str!("fixtures/day06.txt"), 288, 71503);
+}
+
+#[test]
+fn day07() {
+    check::<Day07>(include_str!("fixtures/day07.txt"), 6440, 5905);
+}
+
+#[test]
+fn day08() {
+    check::<Day08>(include_str!("fixtures/day08a.txt"), Some(2), Some(2));
+    check::<Day08>(include_str!("fixtures/day08c.txt"), Some(6), Some(6));
+    check::<Day08>(include_str!("fixtures/day08b.txt"), None, Some(6));
+}
+
+#[test]
+fn day09() {
+    check::<Day09>(include_str!("fixtures/day09.txt"), 114, 2);
+}
+
+#[test]
+fn day10() {
+    assert_eq!(part1::<Day10>(include_str!("fixtures/day10a.txt")), 8);
+    assert_eq!(part2::<Day10>(include_str!("fixtures/day10b.txt")), 4);
+    assert_eq!(part2::<Day10>(include_str!("fixtures/day10c.txt")), 8);
+    assert_eq!(part2::<Day10>(include_str!("fixtures/day10d.txt")), 10);
+}
+
+#[test]
+fn day11() {
+    check::<Day11>(include_str!("fixtures/day11.txt"), 374, 82000210);
+}
+
+#[test]
+fn day11_non_square_image() {
+    check::<Day11>(include_str!("fixtures/day11_non_square.txt"), 12, 5000002);
+}
+
+#[test]
+fn day12() {
+    assert_eq!(part1::<Day12>(include_str!("fixtures/day12.txt")), 21);
+}
+
+#[test]
+#[ignore = "brute force is too slow for the unfolded records"]
+fn day12_part2() {
+    assert_eq!(part2::<Day12>(include_str!("fixtures/day12.txt")), 525152);
+}
+
+#[test]
+fn day13() {
+    check::<Day13>(include_str!("fixtures/day13.txt"), Some(405), Some(400));
+}
+
+#[test]
+fn day13_pattern_missing_a_reflection() {
+    check::<Day13>("#.\n..\n", None, Some(100));
+    check::<Day13>("##\n##\n", Some(1), None);
+}
+
+#[test]
+fn day13_no_trailing_newline() {
+    check::<Day13>(
+        include_str!("fixtures/day13_no_trailing_newline.txt"),
+        Some(405),
+        Some(400),
+    );
+}
+
+#[test]
+fn day14() {
+    check::<Day14>(include_str!("fixtures/day14.txt"), 136, 64);
+}
+
+#[test]
+fn day15() {
+    check::<Day15>(include_str!("fixtures/day15.txt"), 1320, 145);
+}
+
+#[test]
+fn day16() {
+    check::<Day16>(include_str!("fixtures/day16.txt"), 46, 51);
+}
+
+#[test]
+fn day17() {
+    check::<Day17>(include_str!("fixtures/day17.txt"), Some(102), Some(94));
+    assert_eq!(
+        part2::<Day17>(include_str!("fixtures/day17b.txt")),
+        Some(71)
+    );
+}
+
+#[test]
+fn day17_too_small_for_the_ultra_crucible() {
+    check::<Day17>("12\n34\n", Some(6), None);
+}
+
+#[test]
+fn day18() {
+    assert_eq!(part1::<Day18>(include_str!("fixtures/day18.txt")), 62);
+}
+
+#[test]
+fn day18_part2() {
+    assert_eq!(
+        part2::<Day18>(include_str!("fixtures/day18.txt")),
+        952408144115
+    );
+}
+
+#[test]
+fn day19() {
+    assert_eq!(
+        part1::<Day19>(include_str!("fixtures/day19.txt")),
+        Some(19114)
+    );
+}
+
+#[test]
+fn day19_workflows_in_a_cycle() {
+    let input = "in{x>0:a,R}\na{x>0:b,R}\nb{x>0:a,R}\n\n{x=1,m=2,a=3,s=4}\n";
+    assert_eq!(part1::<Day19>(input), None);
+}
+
+#[test]
+fn day20() {
+    assert_eq!(
+        part1::<Day20>(include_str!("fixtures/day20a.txt")),
+        32000000
+    );
+    assert_eq!(
+        part1::<Day20>(include_str!("fixtures/day20b.txt")),
+        11687500
+    );
+}
+
+#[test]
+fn day20_without_rx_has_no_part2() {
+    let day20 = days::get(20).unwrap();
+    for (input, part1) in [
+        (include_str!("fixtures/day20a.txt"), 32000000),
+        (include_str!("fixtures/day20b.txt"), 11687500),
+    ] {
+        assert_eq!(
+            day20.run(input).unwrap(),
+            (Answer::Unsigned(part1), Answer::Unsolved)
+        );
+    }
+}
+
+#[test]
+fn day21() {
+    let garden = parse::<Day21>(include_str!("fixtures/day21.txt"));
+    assert_eq!(day21::reachable_plots(&garden, 6), 16);
+}
+
+#[test]
+fn day21_part2() {
+    let garden = parse::<Day21>(include_str!("fixtures/day21.txt"));
+    for (steps, plots) in [
+        (6, 16),
+        (10, 50),
+        (50, 1594),
+        (100, 6536),
+        (500, 167004),
+        (1000, 668697),
+        (5000, 16733044),
+    ] {
+        assert_eq!(
+            day21::infinite_reachable_plots(&garden, steps),
+            plots,
+            "after {steps} steps"
+        );
+    }
+}
+
+#[test]
+fn day22() {
+    check::<Day22>(include_str!("fixtures/day22.txt"), 5, 7);
+}
+
+#[test]
+fn day23() {
+    check::<Day23>(include_str!("fixtures/day23.txt"), 94, 154);
+}
+
+#[test]
+fn day24() {
+    let hailstones = parse::<Day24>(include_str!("fixtures/day24.txt"));
+    assert_eq!(day24::count_intersections(&hailstones, 7.0, 27.0), 2);
+}
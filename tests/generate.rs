@@ -0,0 +1,66 @@
+use adventofcode_2023::{
+    days,
+    generate::{self, Rng},
+    solution::{Answer, Part},
+};
+
+#[test]
+fn generated_inputs_parse() {
+    for day in 1..=days::COUNT {
+        for (size, seed) in [(1, 0), (5, 1), (12, 2)] {
+            let input = generate::generate(day, size, &mut Rng::new(seed)).unwrap();
+            let report = days::get(day).unwrap().report(&input, &[]);
+            if let Err(err) = report.result {
+                panic!("day {day}, size {size}, seed {seed}: {err}\n{input}");
+            }
+        }
+    }
+}
+
+#[test]
+fn small_generated_inputs_solve() {
+    for day in 1..=days::COUNT {
+        // Day 12's second part tries every arrangement of the unfolded
+        // springs, which is out of reach even for a single short record.
+        let parts: &[Part] = if day == 12 {
+            &[Part::One]
+        } else {
+            &[Part::One, Part::Two]
+        };
+        for (size, seed) in [(1, 0), (2, 1), (3, 2)] {
+            let input = generate::generate(day, size, &mut Rng::new(seed)).unwrap();
+            let report = days::get(day).unwrap().report(&input, parts);
+            if let Err(err) = report.result {
+                panic!("day {day}, size {size}, seed {seed}: {err}\n{input}");
+            }
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_are_deterministic() {
+    for day in 1..=days::COUNT {
+        let first = generate::generate(day, 8, &mut Rng::new(42));
+        let second = generate::generate(day, 8, &mut Rng::new(42));
+        assert_eq!(first, second, "day {day}");
+    }
+}
+
+#[test]
+fn generated_inputs_have_the_structure_solvers_rely_on() {
+    for seed in 0..5 {
+        let solve = |day, size| {
+            let input = generate::generate(day, size, &mut Rng::new(seed)).unwrap();
+            days::get(day).unwrap().run(&input).unwrap()
+        };
+
+        // Every pattern has a reflection, and another one smudge away.
+        let (part1, part2) = solve(13, 10);
+        assert_ne!(part1, Answer::Unsolved, "day 13, seed {seed}");
+        assert_ne!(part2, Answer::Unsolved, "day 13, seed {seed}");
+        // The counters eventually line up and send `rx` a low pulse.
+        solve(20, 3);
+        // There is a hike from the start to the end.
+        assert_ne!(solve(23, 3).0.to_string(), "0", "day 23, seed {seed}");
+    }
+}
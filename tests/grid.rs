@@ -0,0 +1,571 @@
+use adventofcode_2023::{
+    error::{Error, Location},
+    grid::{
+        Actor, Axis, Color, Component, Connectivity, Direction, Grid, Layer, Mismatch, MoveOutcome,
+        Palette, Position, RelativeDirection, SignedPosition, SparseGrid, Tiling, Transform,
+        Vector,
+    },
+    input::invalid_tile,
+};
+
+fn grid(rows: &[&str]) -> Grid<char> {
+    Grid::from_rows(rows.iter().map(|row| row.chars().collect()).collect()).unwrap()
+}
+
+#[test]
+fn cells_are_stored_row_by_row() {
+    let mut grid = grid(&["abc", "def"]);
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.cells(), ['a', 'b', 'c', 'd', 'e', 'f']);
+
+    assert_eq!(grid[1], ['d', 'e', 'f']);
+    assert_eq!(grid[1][2], 'f');
+    assert_eq!(grid[Position(2, 1)], 'f');
+    assert_eq!(grid.get_pos(Position(3, 0)), None);
+
+    grid[Position(0, 1)] = 'x';
+    grid.swap(Position(0, 0), Position(2, 1));
+    assert_eq!(grid.to_string(), "fbc\nxea");
+}
+
+#[test]
+fn rows_columns_and_positions_iterate_the_grid() {
+    let grid = grid(&["abc", "def"]);
+    assert_eq!(
+        grid.rows().collect::<Vec<_>>(),
+        [['a', 'b', 'c'], ['d', 'e', 'f']]
+    );
+    assert_eq!(
+        grid.columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>(),
+        ["ad", "be", "cf"]
+    );
+    assert_eq!(grid.column(2).rev().collect::<String>(), "fc");
+    assert_eq!(grid.positions().len(), 6);
+    assert_eq!(grid.enumerate().nth(4), Some((Position(1, 1), &'e')));
+    assert_eq!(grid.enumerate().last(), Some((Position(2, 1), &'f')));
+}
+
+#[test]
+fn writes_through_a_column_reach_the_grid() {
+    let mut grid = grid(&["abc", "def", "ghi"]);
+    let mut column = grid.column_mut(1);
+    assert_eq!(column.len(), 3);
+    assert_eq!(column.get(3), None);
+    column[0] = 'x';
+    column.swap(1, 2);
+    for cell in column.iter_mut() {
+        cell.make_ascii_uppercase();
+    }
+    assert_eq!(column.iter().collect::<String>(), "XHE");
+    assert_eq!(grid.to_string(), "aXc\ndHf\ngEi");
+}
+
+#[test]
+fn rows_must_be_the_same_length() {
+    assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    assert_eq!(
+        Grid::<u8>::from_rows(Vec::new()),
+        Some(Grid::new(0, 0, Vec::new()))
+    );
+    assert_eq!(
+        Grid::from_fn(2, 2, |pos| pos.0 + 10 * pos.1),
+        Grid::new(2, 2, vec![0, 1, 10, 11])
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            c => Err(invalid_tile(c, "`.` or `#`")),
+        }
+    }
+}
+
+#[test]
+fn parse_skips_blank_lines() {
+    let grid: Grid<char> = Grid::parse("\nab\n\ncd\n").unwrap();
+    assert_eq!(grid, Grid::new(2, 2, vec!['a', 'b', 'c', 'd']));
+}
+
+#[test]
+fn parse_rejects_input_without_rows() {
+    for input in ["", "\n\n"] {
+        let err = Grid::<char>::parse(input).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::MissingField {
+                field: "at least one row of tiles",
+                ..
+            }
+        ));
+    }
+}
+
+#[test]
+fn parse_reports_every_invalid_tile_and_ragged_row() {
+    let err = Grid::<Tile>::parse(".#x\n..\n#y.\n.#..\n").unwrap_err();
+    let locations: Vec<Location> = err.as_slice().iter().filter_map(Error::location).collect();
+    assert_eq!(
+        locations,
+        [
+            Location::new(1, 3),
+            Location::new(2, 3),
+            Location::new(3, 2),
+            Location::new(4, 4)
+        ]
+    );
+}
+
+#[test]
+fn parse_replaces_and_records_markers() {
+    let (grid, [starts, ends]) = Grid::parse_with_markers(
+        "S.#\n#.E\n..E\n",
+        [('S', Tile::Open), ('E', Tile::Open)],
+        Tile::try_from,
+    )
+    .unwrap();
+
+    assert_eq!(grid[Position(0, 0)], Tile::Open);
+    assert_eq!(grid[Position(2, 1)], Tile::Open);
+    assert_eq!(starts, [Position(0, 0)]);
+    assert_eq!(ends, [Position(2, 1), Position(2, 2)]);
+}
+
+#[test]
+fn transforms_copy_and_view_the_grid() {
+    let grid = grid(&["abc", "def"]);
+    assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+
+    let view = grid.view(Transform::RotateCw);
+    assert_eq!((view.width(), view.height()), (2, 3));
+    assert_eq!(view[Position(1, 2)], 'c');
+    assert_eq!(view.get(Position(2, 0)), None);
+    assert_eq!(view.to_grid(), grid.rotate_cw());
+}
+
+#[test]
+fn transforms_map_positions_and_directions_back() {
+    let grid = grid(&["abc", "def"]);
+    for transform in [
+        Transform::Identity,
+        Transform::RotateCw,
+        Transform::RotateCcw,
+        Transform::Transpose,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+    ] {
+        let view = grid.view(transform);
+        for pos in [Position(0, 0), Position(2, 0), Position(1, 1)] {
+            let moved = transform.position(pos, grid.width(), grid.height());
+            assert_eq!(view[moved], grid[pos]);
+            assert_eq!(view.original_position(moved), pos);
+        }
+        for &direction in Direction::all() {
+            assert_eq!(
+                transform
+                    .inverse()
+                    .direction(transform.direction(direction)),
+                direction
+            );
+        }
+    }
+    assert_eq!(
+        Transform::RotateCw.direction(Direction::North),
+        Direction::East
+    );
+    assert_eq!(
+        Transform::Transpose.direction(Direction::East),
+        Direction::South
+    );
+}
+
+#[test]
+fn writes_through_a_view_reach_the_grid() {
+    let mut grid = grid(&["abc", "def"]);
+    let mut view = grid.view_mut(Transform::RotateCcw);
+    view[Position(0, 0)] = 'x';
+    view.swap(Position(0, 2), Position(1, 2));
+    assert_eq!(grid.to_string(), "dbx\naef");
+}
+
+#[test]
+fn neighbors_stay_within_the_grid() {
+    let grid = grid(&["abc", "def", "ghi"]);
+    let neighbors: Vec<_> = grid.neighbors4(Position(0, 0)).collect();
+    assert_eq!(
+        neighbors,
+        [
+            (Direction::South, Position(0, 1), &'d'),
+            (Direction::East, Position(1, 0), &'b')
+        ]
+    );
+
+    let tiles: String = grid
+        .neighbors8(Position(1, 1))
+        .map(|(_, _, c)| *c)
+        .collect();
+    assert_eq!(tiles, "bcfihgda");
+    let corner: Vec<_> = grid.neighbors8(Position(2, 2)).collect();
+    assert_eq!(
+        corner,
+        [
+            (Direction::North, Position(2, 1), &'f'),
+            (Direction::West, Position(1, 2), &'h'),
+            (Direction::NorthWest, Position(1, 1), &'e')
+        ]
+    );
+
+    let vowels: Vec<_> = grid
+        .neighbors4_where(Position(1, 0), |c| "aeiou".contains(*c))
+        .map(|(direction, _, _)| direction)
+        .collect();
+    assert_eq!(vowels, [Direction::South, Direction::West]);
+}
+
+#[test]
+fn wrapping_neighbors_repeat_the_grid() {
+    let grid = grid(&["abc", "def"]);
+    let neighbors: Vec<_> = grid.wrapping_neighbors4(SignedPosition(0, 0)).collect();
+    assert_eq!(
+        neighbors,
+        [
+            (Direction::North, SignedPosition(0, -1), &'d'),
+            (Direction::South, SignedPosition(0, 1), &'d'),
+            (Direction::East, SignedPosition(1, 0), &'b'),
+            (Direction::West, SignedPosition(-1, 0), &'c')
+        ]
+    );
+}
+
+#[test]
+fn steps_and_moves_stay_on_the_grid() {
+    let grid = grid(&["abc", "def"]);
+    assert_eq!(
+        grid.step(Position(0, 0), Direction::East, 2),
+        Some(Position(2, 0))
+    );
+    assert_eq!(grid.step(Position(0, 0), Direction::East, 3), None);
+    assert_eq!(grid.step(Position(0, 0), Direction::North, 1), None);
+
+    let mut actor = Actor {
+        pos: Position(1, 0),
+        vector: Vector(1, 1),
+    };
+    assert_eq!(grid.move_actor(&mut actor), MoveOutcome::Moved);
+    assert_eq!(actor.pos, Position(2, 1));
+    assert_eq!(grid.move_actor(&mut actor), MoveOutcome::HitBoundary);
+    assert_eq!(actor.pos, Position(2, 1));
+
+    actor.vector = Vector(-3, 0);
+    assert_eq!(grid.move_actor(&mut actor), MoveOutcome::HitBoundary);
+    assert_eq!(actor.pos, Position(2, 1));
+}
+
+#[test]
+fn moves_off_every_edge_hit_the_boundary_or_wrap() {
+    let grid = grid(&["abc", "def"]);
+    for (pos, direction, wrapped) in [
+        (Position(1, 0), Direction::North, Position(1, 1)),
+        (Position(2, 1), Direction::East, Position(0, 1)),
+        (Position(1, 1), Direction::South, Position(1, 0)),
+        (Position(0, 0), Direction::West, Position(2, 0)),
+    ] {
+        let mut actor = Actor {
+            pos,
+            vector: direction.unit_vector(),
+        };
+        assert_eq!(grid.step(pos, direction, 1), None, "{direction:?}");
+        assert_eq!(
+            grid.move_actor(&mut actor),
+            MoveOutcome::HitBoundary,
+            "{direction:?}"
+        );
+        assert_eq!(actor.pos, pos, "{direction:?}");
+
+        assert_eq!(
+            grid.move_actor_wrapping(&mut actor),
+            MoveOutcome::WouldWrap,
+            "{direction:?}"
+        );
+        assert_eq!(actor.pos, wrapped, "{direction:?}");
+    }
+
+    let mut actor = Actor {
+        pos: Position(0, 0),
+        vector: Vector(1, 1),
+    };
+    assert_eq!(grid.move_actor_wrapping(&mut actor), MoveOutcome::Moved);
+    assert_eq!(actor.pos, Position(1, 1));
+}
+
+#[test]
+fn directions_turn_in_eighths() {
+    assert_eq!(
+        Direction::North.turned(RelativeDirection::HalfRight),
+        Direction::NorthEast
+    );
+    assert_eq!(
+        Direction::SouthWest.turned(RelativeDirection::Right),
+        Direction::NorthWest
+    );
+    assert_eq!(
+        Direction::NorthWest.turned(RelativeDirection::Backward),
+        Direction::SouthEast
+    );
+    assert_eq!(Vector(-3, 3).direction(), Some(Direction::SouthWest));
+    assert_eq!(Vector(2, 1).direction(), None);
+    assert_eq!(
+        Transform::Transpose.direction(Direction::NorthEast),
+        Direction::SouthWest
+    );
+}
+
+#[test]
+fn any_vector_can_be_rotated() {
+    assert_eq!(
+        Vector(2, 0).rotated(RelativeDirection::HalfRight),
+        Vector(2, 2)
+    );
+    assert_eq!(
+        Vector(2, 1).rotated(RelativeDirection::Right),
+        Vector(-1, 2)
+    );
+    assert_eq!(Vector(2, 1).rotated(RelativeDirection::Left), Vector(1, -2));
+    assert_eq!(
+        Vector(2, 1).rotated(RelativeDirection::Backward),
+        Vector(-2, -1)
+    );
+
+    let v = Vector(3, -1);
+    let twice = v
+        .rotated(RelativeDirection::HalfLeft)
+        .rotated(RelativeDirection::HalfLeft);
+    assert_eq!(twice, v.rotated(RelativeDirection::Left));
+
+    let mut actor = Actor {
+        pos: Position(0, 0),
+        vector: Vector(1, 1),
+    };
+    actor.turn(RelativeDirection::HalfLeft);
+    assert_eq!(actor.vector, Vector(1, 0));
+    actor.turn(RelativeDirection::Left);
+    assert_eq!(actor.vector, Vector(0, -1));
+}
+
+#[test]
+fn flood_fill_follows_the_chosen_neighbors() {
+    let grid = grid(&["a.b", ".a.", "a.a"]);
+    let four = grid.flood_fill(Position(1, 1), Connectivity::Four, |c| *c == 'a');
+    assert_eq!(four, [Position(1, 1)]);
+
+    let eight = grid.flood_fill(Position(1, 1), Connectivity::Eight, |c| *c == 'a');
+    assert_eq!(eight.len(), 4);
+    assert!(grid
+        .flood_fill(Position(1, 0), Connectivity::Eight, |c| *c == 'a')
+        .is_empty());
+}
+
+#[test]
+fn components_are_labeled_with_their_extent() {
+    let map = grid(&["##..", "##..", "..##", "..##"]);
+    let walls = map.label_components(Connectivity::Four, |c| *c == '#');
+    assert_eq!(
+        walls.components,
+        [
+            Component {
+                size: 4,
+                min: Position(0, 0),
+                max: Position(1, 1),
+                touches_border: true,
+            },
+            Component {
+                size: 4,
+                min: Position(2, 2),
+                max: Position(3, 3),
+                touches_border: true,
+            }
+        ]
+    );
+    assert_eq!(walls.labels[Position(3, 3)], Some(1));
+    assert_eq!(walls.labels[Position(2, 0)], None);
+    assert_eq!(
+        walls.at(Position(0, 1)).map(|c| c.max),
+        Some(Position(1, 1))
+    );
+
+    let ground = map.label_components(Connectivity::Four, |c| *c == '.');
+    assert_eq!(ground.components.len(), 2);
+    let ground = map.label_components(Connectivity::Eight, |c| *c == '.');
+    assert_eq!(ground.components.len(), 1);
+
+    let ring = grid(&["###", "#.#", "###"]);
+    let inside = ring.label_components(Connectivity::Four, |c| *c == '.');
+    assert!(!inside.components[0].touches_border);
+}
+
+#[test]
+fn sparse_grids_grow_to_fit_and_convert_to_dense() {
+    let mut sparse = SparseGrid::new('.');
+    assert_eq!(sparse.bounds(), None);
+    sparse.insert(SignedPosition(-2, 1), '#');
+    sparse.insert(SignedPosition(1, -1), 'o');
+    assert_eq!(
+        sparse.bounds(),
+        Some((SignedPosition(-2, -1), SignedPosition(1, 1)))
+    );
+    assert_eq!(sparse[SignedPosition(0, 0)], '.');
+    assert_eq!(sparse.to_string(), "...o\n....\n#...");
+
+    let (dense, offset) = sparse.to_grid();
+    assert_eq!(offset, SignedPosition(-2, -1));
+    assert_eq!(dense[Position(3, 0)], 'o');
+
+    let round_trip = SparseGrid::from_grid(dense, offset, '.');
+    assert_eq!(round_trip.to_string(), sparse.to_string());
+    assert_eq!(round_trip.bounds(), sparse.bounds());
+}
+
+#[test]
+fn tiled_grids_cover_the_plane() {
+    let grid = grid(&["abc", "def"]);
+    let repeated = grid.tiled(Tiling::Repeat);
+    assert_eq!(
+        repeated.locate(SignedPosition(-1, 2)),
+        (SignedPosition(-1, 1), Position(2, 0))
+    );
+    assert_eq!(
+        repeated.tile_origin(SignedPosition(-1, 1)),
+        SignedPosition(-3, 2)
+    );
+    assert_eq!(*repeated.get(SignedPosition(4, -1)), 'e');
+
+    let mirrored = grid.tiled(Tiling::Mirror);
+    assert_eq!(*mirrored.get(SignedPosition(3, 0)), 'c');
+    assert_eq!(*mirrored.get(SignedPosition(-1, 0)), 'a');
+    assert_eq!(*mirrored.get(SignedPosition(0, -1)), 'a');
+    assert_eq!(*mirrored.get(SignedPosition(4, 3)), 'b');
+
+    let across: String = mirrored
+        .neighbors8(SignedPosition(2, 1))
+        .map(|(_, _, c)| *c)
+        .collect();
+    assert_eq!(across, "ccfffeeb");
+}
+
+#[test]
+fn renderer_draws_layers_over_the_grid() {
+    let grid = grid(&["...", "..."]);
+    let renderer = grid
+        .renderer()
+        .color(false)
+        .layer(Layer::new(
+            "walls",
+            '#',
+            Color::Gray,
+            [Position(0, 0), Position(2, 1)],
+        ))
+        .layer(Layer::path(
+            "path",
+            Color::Green,
+            [
+                (Position(0, 0), Direction::East),
+                (Position(1, 0), Direction::SouthEast),
+            ],
+        ));
+    assert_eq!(renderer.to_string(), "→↘.\n..#");
+    assert_eq!(renderer.legend(), "→ path\n# walls");
+
+    let colored = renderer.color(true).to_string();
+    assert!(colored.starts_with("\x1b[32m→\x1b[0m"));
+    assert!(colored.ends_with("\x1b[90m#\x1b[0m"));
+}
+
+#[test]
+fn grids_export_as_netpbm_images() {
+    let grid = grid(&["#.", ".#"]);
+    let palette = |c: &char| if *c == '#' { [255, 0, 0] } else { [0, 0, 0] };
+
+    let mut ppm = Vec::new();
+    grid.write_ppm(&mut ppm, &palette, 1, &[]).unwrap();
+    assert_eq!(
+        ppm,
+        [
+            b"P6\n2 2\n255\n".as_slice(),
+            &[255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0]
+        ]
+        .concat()
+    );
+
+    let overlay = Layer::new("start", 'S', Color::White, [Position(1, 0)]);
+    let mut pgm = Vec::new();
+    grid.write_pgm(&mut pgm, &palette, 2, &[overlay]).unwrap();
+    assert_eq!(palette.gray(&'#'), 76);
+    assert_eq!(
+        pgm,
+        [
+            b"P5\n4 4\n255\n".as_slice(),
+            &[76, 76, 229, 229],
+            &[76, 76, 229, 229],
+            &[0, 0, 76, 76],
+            &[0, 0, 76, 76]
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn diff_reports_every_mismatch() {
+    let a = grid(&["abc", "def"]);
+    let b = grid(&["abx", "yef", "ghi"]);
+    let positions: Vec<Position> = a.diff(&b).iter().map(|m| m.pos).collect();
+    assert_eq!(
+        positions,
+        [
+            Position(2, 0),
+            Position(0, 1),
+            Position(0, 2),
+            Position(1, 2),
+            Position(2, 2)
+        ]
+    );
+    assert_eq!(
+        a.diff(&b)[0],
+        Mismatch {
+            pos: Position(2, 0),
+            left: Some(&'c'),
+            right: Some(&'x'),
+        }
+    );
+    assert_eq!(a.diff(&b)[2].left, None);
+
+    assert_eq!(a.diff_up_to(&b, 2).len(), 2);
+    assert!(a.diff(&a.clone()).is_empty());
+}
+
+#[test]
+fn mirror_differences_compare_across_an_axis() {
+    let grid = grid(&["abba", "xyyz"]);
+    assert_eq!(
+        grid.mirror_differences(Axis::Column(2)).collect::<Vec<_>>(),
+        [(Position(0, 1), Position(3, 1))]
+    );
+    assert_eq!(grid.mirror_differences(Axis::Column(1)).count(), 2);
+    assert_eq!(grid.mirror_differences(Axis::Row(1)).count(), 4);
+    assert_eq!(grid.mirror_differences(Axis::Row(9)).count(), 0);
+}
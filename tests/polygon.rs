@@ -0,0 +1,122 @@
+use adventofcode_2023::{
+    grid::{Direction, SignedPosition},
+    polygon::{Polygon, PolygonError},
+};
+
+fn square(size: isize) -> Vec<SignedPosition> {
+    vec![
+        SignedPosition(0, 0),
+        SignedPosition(size, 0),
+        SignedPosition(size, size),
+        SignedPosition(0, size),
+        SignedPosition(0, 0),
+    ]
+}
+
+#[test]
+fn square_area_and_lattice_points() {
+    let polygon = Polygon::new(square(4)).unwrap();
+    assert_eq!(polygon.doubled_signed_area(), 32);
+    assert_eq!(polygon.signed_area(), 16);
+    assert_eq!(polygon.boundary_len(), 16);
+    assert_eq!(polygon.interior_points(), 9);
+    assert_eq!(polygon.total_area(), 25);
+
+    let reversed = Polygon::new(square(4).into_iter().rev()).unwrap();
+    assert_eq!(reversed.signed_area(), -16);
+    assert_eq!(reversed.interior_points(), 9);
+}
+
+#[test]
+fn points_along_edges_are_merged_into_corners() {
+    let polygon = Polygon::new(
+        [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (0, 2),
+            (0, 1),
+            (0, 0),
+        ]
+        .map(|(x, y)| SignedPosition(x, y)),
+    )
+    .unwrap();
+    assert_eq!(polygon.vertices(), &square(2)[..4]);
+    assert_eq!(polygon.total_area(), 9);
+}
+
+#[test]
+fn runs_and_diagonal_edges() {
+    let runs = [
+        (Direction::East, 6),
+        (Direction::South, 5),
+        (Direction::West, 2),
+        (Direction::South, 2),
+        (Direction::West, 4),
+        (Direction::North, 7),
+    ];
+    let polygon = Polygon::from_runs(runs).unwrap();
+    assert_eq!(polygon.signed_area(), 38);
+    assert_eq!(polygon.boundary_len(), 26);
+    assert_eq!(polygon.total_area(), 52);
+
+    let triangle = Polygon::from_runs([
+        (Direction::East, 2),
+        (Direction::SouthWest, 1),
+        (Direction::NorthWest, 1),
+    ])
+    .unwrap();
+    assert_eq!(triangle.doubled_signed_area(), 2);
+    assert_eq!(triangle.signed_area(), 1);
+    assert_eq!(triangle.boundary_len(), 4);
+    assert_eq!(triangle.interior_points(), 0);
+}
+
+#[test]
+fn rejects_paths_that_are_not_simple_polygons() {
+    assert_eq!(
+        Polygon::from_runs([(Direction::East, 3), (Direction::South, 2)]),
+        Err(PolygonError::Unclosed {
+            start: SignedPosition(0, 0),
+            end: SignedPosition(3, 2),
+        })
+    );
+    assert_eq!(Polygon::new([]), Err(PolygonError::TooFewVertices));
+    assert_eq!(
+        Polygon::from_runs([(Direction::East, 3), (Direction::West, 3)]),
+        Err(PolygonError::SelfIntersecting {
+            edges: [
+                (SignedPosition(0, 0), SignedPosition(3, 0)),
+                (SignedPosition(3, 0), SignedPosition(0, 0)),
+            ],
+        })
+    );
+
+    // A figure eight, crossing itself in the middle.
+    let bowtie =
+        Polygon::new([(0, 0), (2, 2), (2, 0), (0, 2), (0, 0)].map(|(x, y)| SignedPosition(x, y)));
+    assert!(matches!(bowtie, Err(PolygonError::SelfIntersecting { .. })));
+
+    // Two squares that only touch at a corner, which the path passes twice.
+    let touching = Polygon::new(
+        [
+            (0, 0),
+            (1, 0),
+            (1, 1),
+            (2, 1),
+            (2, 2),
+            (1, 2),
+            (1, 1),
+            (0, 1),
+            (0, 0),
+        ]
+        .map(|(x, y)| SignedPosition(x, y)),
+    );
+    assert!(matches!(
+        touching,
+        Err(PolygonError::SelfIntersecting { .. })
+    ));
+}
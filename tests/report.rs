@@ -0,0 +1,105 @@
+use std::{io, time::Duration};
+
+use adventofcode_2023::{
+    error::Error,
+    report::{json_array, PartReport, Report, CSV_HEADER},
+    solution::{Answer, Part},
+};
+
+fn solved() -> Report {
+    Report {
+        day: 12,
+        parse: Some(Duration::from_nanos(5)),
+        result: Ok(vec![
+            PartReport {
+                part: Part::One,
+                answer: Answer::Unsigned(u128::MAX),
+                solve: Duration::from_nanos(7),
+            },
+            PartReport {
+                part: Part::Two,
+                answer: Answer::Unsolved,
+                solve: Duration::from_nanos(1),
+            },
+        ]),
+    }
+}
+
+/// A failure whose message needs escaping in both JSON and CSV.
+fn failed() -> Report {
+    let err = io::Error::other("bad \"input\", line 1\nline 2\t\u{1}");
+    Report::failed(3, err.into())
+}
+
+#[test]
+fn json_keeps_wide_answers_exact_and_unsolved_parts_null() {
+    assert_eq!(
+        solved().to_json(),
+        "{\"day\":12,\"parse_ns\":5,\"status\":\"ok\",\"error\":null,\"parts\":[\
+         {\"part\":1,\"answer\":\"340282366920938463463374607431768211455\",\"solve_ns\":7},\
+         {\"part\":2,\"answer\":null,\"solve_ns\":1}]}"
+    );
+
+    let negative = Report {
+        day: 9,
+        parse: Some(Duration::ZERO),
+        result: Ok(vec![PartReport {
+            part: Part::Two,
+            answer: Answer::Signed(i128::MIN),
+            solve: Duration::ZERO,
+        }]),
+    };
+    assert!(negative
+        .to_json()
+        .contains("\"answer\":\"-170141183460469231731687303715884105728\""));
+}
+
+#[test]
+fn json_escapes_error_messages() {
+    assert_eq!(
+        failed().to_json(),
+        "{\"day\":3,\"parse_ns\":null,\"status\":\"error\",\
+         \"error\":\"i/o error: bad \\\"input\\\", line 1\\nline 2\\t\\u0001\",\"parts\":[]}"
+    );
+}
+
+#[test]
+fn json_array_frames_every_report() {
+    assert_eq!(json_array(&[]), "[]");
+    assert_eq!(
+        json_array(&[solved(), failed()]),
+        format!("[{},{}]", solved().to_json(), failed().to_json())
+    );
+}
+
+#[test]
+fn csv_has_a_row_per_part_matching_the_header() {
+    assert_eq!(CSV_HEADER.split(',').count(), 7);
+    assert_eq!(
+        solved().csv_rows(),
+        [
+            "12,1,340282366920938463463374607431768211455,5,7,ok,",
+            "12,2,,5,1,ok,",
+        ]
+    );
+}
+
+#[test]
+fn csv_quotes_error_messages_and_has_a_row_per_problem() {
+    assert_eq!(
+        failed().csv_rows(),
+        ["3,,,,,error,\"i/o error: bad \"\"input\"\", line 1\nline 2\t\u{1}\""]
+    );
+
+    let errors = Error::Multiple(vec![
+        Error::invalid_token("x", "x", "a number"),
+        Error::missing_field("", "distance"),
+    ]);
+    assert_eq!(
+        Report::failed(2, errors).csv_rows(),
+        [
+            "2,,,,,error,\"line 1, column 1: invalid token `x`, expected a number\"",
+            "2,,,,,error,\"line 1, column 1: missing distance\"",
+        ]
+    );
+}
@@ -0,0 +1,76 @@
+use adventofcode_2023::{
+    grid::{Grid, Position},
+    search::{astar, bfs, dijkstra},
+};
+
+fn maze() -> Grid<char> {
+    Grid::parse("S..#\n.#.#\n.#..\n...E\n").unwrap()
+}
+
+fn manhattan(a: Position, b: Position) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[test]
+fn bfs_finds_the_fewest_steps() {
+    let grid = maze();
+    let search = bfs(
+        Position(0, 0),
+        |&pos| {
+            grid.neighbors4_where(pos, |c| *c != '#')
+                .map(|(_, next, _)| next)
+                .collect::<Vec<_>>()
+        },
+        |pos| grid[*pos] == 'E',
+    );
+
+    let path = search.path.unwrap();
+    assert_eq!(path.cost, 6);
+    assert_eq!(path.states.len(), 7);
+    assert_eq!(path.states.first(), Some(&Position(0, 0)));
+    assert_eq!(path.states.last(), Some(&Position(3, 3)));
+    assert!(search.expanded > 0);
+}
+
+#[test]
+fn weighted_searches_agree_and_heuristics_save_work() {
+    let costs = Grid::from_fn(20, 20, |pos| 1 + (pos.0 * 7 + pos.1 * 3) % 5);
+    let goal = Position(19, 19);
+    let successors = |pos: &Position| {
+        costs
+            .neighbors4(*pos)
+            .map(|(_, next, cost)| (next, *cost))
+            .collect::<Vec<_>>()
+    };
+
+    let plain = dijkstra(Position(0, 0), successors, |pos| *pos == goal);
+    let guided = astar(
+        Position(0, 0),
+        successors,
+        |pos| manhattan(*pos, goal),
+        |pos| *pos == goal,
+    );
+
+    let (plain_path, guided_path) = (plain.path.unwrap(), guided.path.unwrap());
+    assert_eq!(plain_path.cost, guided_path.cost);
+    let walked: usize = guided_path.states[1..].iter().map(|pos| costs[*pos]).sum();
+    assert_eq!(walked, guided_path.cost);
+    assert!(guided.expanded < plain.expanded);
+}
+
+#[test]
+fn unreachable_goals_report_the_work_done() {
+    let grid = maze();
+    let search = bfs(
+        Position(0, 0),
+        |&pos| {
+            grid.neighbors4_where(pos, |c| *c != '#')
+                .map(|(_, next, _)| next)
+                .collect::<Vec<_>>()
+        },
+        |pos| *pos == Position(3, 0),
+    );
+
+    assert_eq!(search.path, None);
+    assert_eq!(search.expanded, 12);
+}